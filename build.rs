@@ -0,0 +1,180 @@
+//! Discovers solutions under `src/yearNNNN/dayNN.rs` and generates the module
+//! declarations and the `solutions!` registration for them, so a new day only
+//! needs its own file. Malformed or duplicate days fail the build.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+#[path = "src/unlock/days.rs"]
+mod days;
+
+/// Parses the digits following `prefix` in `name`, if there are exactly `width`.
+fn numbered(name: &str, prefix: &str, suffix: &str, width: usize) -> Option<u32> {
+    let digits = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    (digits.len() == width && digits.bytes().all(|b| b.is_ascii_digit()))
+        .then(|| digits.parse().ok())
+        .flatten()
+}
+
+/// Finds the value assigned to `const <name>: <ty> = ` in `source`.
+fn constant<'a>(source: &'a str, name: &str, ty: &str) -> Vec<&'a str> {
+    let needle = format!("const {}: {} = ", name, ty);
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(needle.as_str()))
+        .map(|value| value.trim_end_matches(';').trim())
+        .collect()
+}
+
+fn check_year(dir: &Path, year: u32) {
+    let path = dir.join("mod.rs");
+    let source = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("{} has no mod.rs declaring YEAR", dir.display()));
+
+    let declared = constant(&source, "YEAR", "i32");
+    if declared != [year.to_string()] {
+        panic!(
+            "{} must declare `const YEAR: i32 = {};`, found {:?}",
+            path.display(),
+            year,
+            declared
+        );
+    }
+}
+
+fn check_day(path: &Path, year: u32, day: u32) {
+    let source = fs::read_to_string(path).unwrap();
+    let name = format!("Day{:02}", day);
+
+    let days = days::days_in(year as i32);
+    if !(1..=days).contains(&day) {
+        panic!(
+            "{} is not a day of the {} event, which has days 01 to {:02}",
+            path.display(),
+            year,
+            days
+        );
+    }
+    if !source.contains(&format!("pub struct {};", name)) {
+        panic!("{} must define `pub struct {};`", path.display(), name);
+    }
+    if !source.contains(&format!("impl Solution for {} ", name)) {
+        panic!("{} must implement Solution for {}", path.display(), name);
+    }
+
+    // The year comes from the directory's `YEAR`, or is spelled out to match.
+    let declared = constant(&source, "YEAR", "i32");
+    if declared != ["YEAR"] && declared != [year.to_string()] {
+        panic!(
+            "{} must declare `const YEAR: i32 = YEAR;` exactly once, found {:?}",
+            path.display(),
+            declared
+        );
+    }
+
+    let declared = constant(&source, "DAY", "u32");
+    if declared != [day.to_string()] {
+        panic!(
+            "{} must declare `const DAY: u32 = {};` exactly once, found {:?}",
+            path.display(),
+            day,
+            declared
+        );
+    }
+}
+
+/// Whether a day declares inline `EXAMPLES` or has a fixture file.
+fn has_examples(path: &Path, fixture: &Path) -> bool {
+    fixture.exists() || fs::read_to_string(path).unwrap().contains("const EXAMPLES")
+}
+
+fn discover(src: &Path) -> BTreeMap<u32, BTreeMap<u32, PathBuf>> {
+    let mut years = BTreeMap::new();
+
+    for entry in fs::read_dir(src).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        if !path.is_dir() || !name.starts_with("year") {
+            continue;
+        }
+
+        let year = numbered(&name, "year", "", 4)
+            .unwrap_or_else(|| panic!("{} must be named yearNNNN", path.display()));
+        check_year(&path, year);
+
+        let mut days = BTreeMap::new();
+        for entry in fs::read_dir(&path).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            if !name.starts_with("day") {
+                continue;
+            }
+
+            let day = numbered(&name, "day", ".rs", 2)
+                .unwrap_or_else(|| panic!("{} must be named dayNN.rs", path.display()));
+            check_day(&path, year, day);
+
+            if let Some(existing) = days.insert(day, path.clone()) {
+                panic!(
+                    "Day {} of {} is registered twice: {} and {}",
+                    day,
+                    year,
+                    existing.display(),
+                    path.display()
+                );
+            }
+        }
+
+        years.insert(year, days);
+    }
+
+    years
+}
+
+fn main() {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    let fixtures = src.with_file_name("fixtures");
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=fixtures");
+
+    let years = discover(&src);
+
+    let mut modules = String::new();
+    let mut registry = String::from("solutions! {\n");
+
+    for (year, days) in &years {
+        let dir = src.join(format!("year{}", year));
+        modules.push_str(&format!(
+            "#[path = {:?}]\nmod year{};\n",
+            dir.join("mod.rs"),
+            year
+        ));
+
+        let mut declarations = String::new();
+        registry.push_str(&format!("    year{} => [\n", year));
+        for (day, path) in days {
+            declarations.push_str(&format!(
+                "#[path = {:?}]\npub(crate) mod day{:02};\n",
+                path, day
+            ));
+            // Days without examples still get a test, ignored so the summary
+            // shows what is unchecked rather than passing vacuously.
+            if !has_examples(path, &fixtures.join(format!("{}/{:02}.yaml", year, day))) {
+                registry.push_str("        #[ignore = \"no examples\"]\n");
+            }
+            registry.push_str(&format!("        day{:02}::Day{:02},\n", day, day));
+        }
+        registry.push_str("    ],\n");
+
+        fs::write(out.join(format!("year{}.rs", year)), declarations).unwrap();
+    }
+
+    registry.push_str("}\n");
+
+    fs::write(out.join("years.rs"), modules).unwrap();
+    fs::write(out.join("solutions.rs"), registry).unwrap();
+}
@@ -0,0 +1,232 @@
+use std::{collections::BTreeMap, fs, path::Path, time::Duration};
+
+use anyhow::{anyhow, ensure, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge::Fetcher,
+    runner::{self, Solutions},
+    solution::DynSolution,
+};
+
+const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        Some(Self {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Median nanoseconds for parse, part 1 and part 2, keyed by `year/day`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, [Option<u64>; 3]>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let yaml = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Could not read baseline {}: {}", path.display(), e))?;
+        Ok(serde_yaml::from_str(&yaml)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_yaml::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn get(&self, year: i32, day: u32) -> Option<&[Option<u64>; 3]> {
+        self.0.get(&key(year, day))
+    }
+
+    /// Sets a day's medians, replacing any it had and keeping other days.
+    fn record(&mut self, year: i32, day: u32, stats: &[Option<Stats>; 3]) {
+        self.0.insert(
+            key(year, day),
+            stats.map(|s| s.map(|s| s.median.as_nanos() as u64)),
+        );
+    }
+}
+
+/// How much slower `median` is than a baseline of `old` nanoseconds, as a
+/// percentage; negative when faster.
+fn slowdown(old: u64, median: Duration) -> Option<f64> {
+    (old > 0).then(|| (median.as_nanos() as f64 / old as f64 - 1.0) * 100.0)
+}
+
+fn key(year: i32, day: u32) -> String {
+    format!("{}/{:02}", year, day)
+}
+
+/// Runs every step of a solution `runs` times against the same input. A
+/// panic in the solution is returned as an error.
+pub fn measure(solution: &dyn DynSolution, input: &str, runs: usize) -> Result<[Option<Stats>; 3]> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+
+    for _ in 0..runs {
+        let solved = runner::solve_caught(solution, input, None)?;
+        samples[0].push(solved.parse);
+        for (i, elapsed) in solved.parts.iter().enumerate() {
+            if let Some(elapsed) = elapsed {
+                samples[i + 1].push(*elapsed);
+            }
+        }
+    }
+
+    Ok(samples.map(Stats::from_samples))
+}
+
+pub struct Options<'a> {
+    pub runs: usize,
+    pub save: Option<&'a Path>,
+    pub compare: Option<&'a Path>,
+    /// Percentage slowdown of a median over the baseline counted as a regression.
+    pub threshold: f64,
+}
+
+pub fn run(
+    fetcher: &Fetcher,
+    solutions: &Solutions,
+    year: i32,
+    day: Option<i32>,
+    options: Options,
+) -> Result<()> {
+    let days: Vec<(i32, &dyn DynSolution)> = match day {
+        Some(day) => vec![(day, runner::find(solutions, year, day)?)],
+        None => solutions
+            .get(&year)
+            .ok_or_else(|| anyhow!("No solutions found for Year {}", year))?
+            .iter()
+            .map(|(d, solution)| (*d, *solution))
+            .collect(),
+    };
+    ensure!(
+        days.len() == 1 || !fetcher.input_source().is_single(),
+        "{} can only be used for a single day, but {} are selected",
+        fetcher.input_source(),
+        days.len()
+    );
+
+    let previous = options.compare.map(Baseline::load).transpose()?;
+    // New timings are merged into an existing baseline, so benchmarking one
+    // day keeps the rest.
+    let mut baseline = match options.save {
+        Some(path) if path.exists() => Baseline::load(path)?,
+        _ => Baseline::default(),
+    };
+    let mut regressions = 0;
+    let mut failures = 0;
+
+    println!(
+        "{:>4} {:>3}  {:<5} {:>10} {:>10} {:>10}  Change",
+        "Year", "Day", "Step", "Min", "Median", "Max"
+    );
+
+    for (d, solution) in days {
+        let input = fetcher.fetch_challenge(year, d as u32)?;
+        let stats = match measure(solution, &input, options.runs) {
+            Ok(stats) => stats,
+            Err(e) => {
+                failures += 1;
+                println!("{:>4} {:>3}  failed: {:#}", year, d, e);
+                continue;
+            }
+        };
+        let before = previous.as_ref().and_then(|b| b.get(year, d as u32));
+
+        for (i, stats) in stats.iter().enumerate() {
+            let Some(stats) = stats else {
+                continue;
+            };
+
+            let change = match before.and_then(|b| slowdown(b[i]?, stats.median)) {
+                Some(pct) => {
+                    if pct > options.threshold {
+                        regressions += 1;
+                        format!("{:+.1}% REGRESSION", pct)
+                    } else {
+                        format!("{:+.1}%", pct)
+                    }
+                }
+                None => String::new(),
+            };
+
+            println!(
+                "{:>4} {:>3}  {:<5} {:>10} {:>10} {:>10}  {}",
+                year,
+                d,
+                STEPS[i],
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+                change
+            );
+        }
+
+        baseline.record(year, d as u32, &stats);
+    }
+
+    if let Some(path) = options.save {
+        baseline.save(path)?;
+        println!("Baseline saved to {}", path.display());
+    }
+
+    ensure!(failures == 0, "{} day(s) failed", failures);
+    match regressions {
+        0 => Ok(()),
+        n => Err(anyhow!(
+            "{} step(s) regressed by more than {}%",
+            n,
+            options.threshold
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats_take_the_middle_sample() {
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3), ms(9)]).unwrap();
+
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(5), ms(9)));
+        assert!(Stats::from_samples(vec![]).is_none());
+    }
+
+    #[test]
+    fn compares_medians_against_the_baseline() {
+        assert_eq!(slowdown(1_000_000, ms(2)), Some(100.0));
+        assert_eq!(slowdown(2_000_000, ms(1)), Some(-50.0));
+        assert_eq!(slowdown(0, ms(1)), None);
+    }
+
+    #[test]
+    fn recording_a_day_keeps_the_others() {
+        let mut baseline: Baseline =
+            serde_yaml::from_str("2015/01: [10, 20, 30]\n2015/02: [1, 2, null]\n").unwrap();
+        let stats = Stats::from_samples(vec![ms(4)]);
+
+        baseline.record(2015, 2, &[stats, stats, None]);
+
+        assert_eq!(baseline.get(2015, 1), Some(&[Some(10), Some(20), Some(30)]));
+        assert_eq!(
+            baseline.get(2015, 2),
+            Some(&[Some(4_000_000), Some(4_000_000), None])
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Result;
+
+const INPUT_FILE: &str = "input.txt";
+const PUZZLE_FILE: &str = "puzzle.md";
+const STARS_FILE: &str = "stars.yaml";
+
+/// On-disk store for everything fetched from adventofcode.com, laid out as
+/// `<cache dir>/<profile>/<year>/<day>/`.
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: &Path, profile: &str) -> Self {
+        Self {
+            root: dir.join(profile),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn day_dir(&self, year: i32, day: u32) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("{:02}", day))
+    }
+
+    pub fn read(&self, year: i32, day: u32, name: &str) -> Option<String> {
+        fs::read_to_string(self.day_dir(year, day).join(name)).ok()
+    }
+
+    pub fn write(&self, year: i32, day: u32, name: &str, contents: &str) -> Result<()> {
+        let dir = self.day_dir(year, day);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(name), contents)?;
+        Ok(())
+    }
+
+    pub fn read_input(&self, year: i32, day: u32) -> Option<String> {
+        self.read(year, day, INPUT_FILE)
+    }
+
+    pub fn write_input(&self, year: i32, day: u32, input: &str) -> Result<()> {
+        self.write(year, day, INPUT_FILE, input)
+    }
+
+    pub fn read_puzzle(&self, year: i32, day: u32) -> Option<String> {
+        self.read(year, day, PUZZLE_FILE)
+    }
+
+    pub fn write_puzzle(&self, year: i32, day: u32, markdown: &str) -> Result<()> {
+        self.write(year, day, PUZZLE_FILE, markdown)
+    }
+
+    /// Drops a cached description so the next fetch picks up newly unlocked parts.
+    pub fn remove_puzzle(&self, year: i32, day: u32) -> Result<()> {
+        let path = self.day_dir(year, day).join(PUZZLE_FILE);
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    fn leaderboard_path(&self, year: i32, id: u64) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join("leaderboard")
+            .join(format!("{}.json", id))
+    }
+
+    /// Returns a cached private leaderboard unless it is older than `max_age`.
+    pub fn read_leaderboard(&self, year: i32, id: u64, max_age: Duration) -> Option<String> {
+        read_fresh(&self.leaderboard_path(year, id), max_age)
+    }
+
+    pub fn write_leaderboard(&self, year: i32, id: u64, json: &str) -> Result<()> {
+        let path = self.leaderboard_path(year, id);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn stars_path(&self, year: i32) -> PathBuf {
+        self.root.join(year.to_string()).join(STARS_FILE)
+    }
+
+    /// Returns the cached stars per day for a year unless older than `max_age`.
+    pub fn read_stars(&self, year: i32, max_age: Duration) -> Option<BTreeMap<u32, u8>> {
+        serde_yaml::from_str(&read_fresh(&self.stars_path(year), max_age)?).ok()
+    }
+
+    pub fn write_stars(&self, year: i32, stars: &BTreeMap<u32, u8>) -> Result<()> {
+        let path = self.stars_path(year);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, serde_yaml::to_string(stars)?)?;
+        Ok(())
+    }
+
+    /// Drops a year's cached stars once an answer has earned a new one.
+    pub fn remove_stars(&self, year: i32) -> Result<()> {
+        let path = self.stars_path(year);
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Lists every year and day with a cached input, in order.
+    pub fn list_inputs(&self) -> Result<Vec<(i32, u32)>> {
+        let mut inputs = Vec::new();
+
+        for (year, year_dir) in numbered_entries(&self.root)? {
+            for (day, day_dir) in numbered_entries(&year_dir)? {
+                if day_dir.join(INPUT_FILE).is_file() {
+                    inputs.push((year, day as u32));
+                }
+            }
+        }
+
+        inputs.sort();
+        Ok(inputs)
+    }
+
+    /// Removes cached files for a single day, a whole year, or everything,
+    /// returning how many days were cleared.
+    pub fn clear(&self, year: Option<i32>, day: Option<u32>) -> Result<usize> {
+        let mut cleared = 0;
+
+        for (y, year_dir) in numbered_entries(&self.root)? {
+            if year.is_some_and(|year| year != y) {
+                continue;
+            }
+
+            for (d, day_dir) in numbered_entries(&year_dir)? {
+                if day.is_some_and(|day| day as i32 != d) {
+                    continue;
+                }
+                fs::remove_dir_all(day_dir)?;
+                cleared += 1;
+            }
+
+            if fs::read_dir(&year_dir)?.next().is_none() {
+                fs::remove_dir(year_dir)?;
+            }
+        }
+
+        Ok(cleared)
+    }
+}
+
+fn read_fresh(path: &Path, max_age: Duration) -> Option<String> {
+    let age = fs::metadata(path).ok()?.modified().ok()?.elapsed().ok()?;
+    (age < max_age).then(|| fs::read_to_string(path).ok())?
+}
+
+fn numbered_entries(dir: &Path) -> Result<Vec<(i32, PathBuf)>> {
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    Ok(fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let n = entry.file_name().to_str()?.parse::<i32>().ok()?;
+            Some((n, entry.path()))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A cache in a fresh directory of its own, removed when dropped.
+    struct Scratch(Cache);
+
+    impl Scratch {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            Scratch(Cache::new(&dir, "default"))
+        }
+    }
+
+    impl Drop for Scratch {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(self.0.root().parent().unwrap());
+        }
+    }
+
+    fn filled(name: &str) -> Scratch {
+        let cache = Scratch::new(name);
+        for (year, day) in [(2024, 2), (2015, 1), (2024, 1)] {
+            cache.0.write_input(year, day, "input").unwrap();
+        }
+        cache.0.write_puzzle(2015, 3, "puzzle").unwrap();
+        cache
+    }
+
+    #[test]
+    fn lists_only_days_with_an_input_in_order() {
+        let cache = filled("list");
+
+        assert_eq!(
+            cache.0.list_inputs().unwrap(),
+            [(2015, 1), (2024, 1), (2024, 2)]
+        );
+        assert!(Scratch::new("empty").0.list_inputs().unwrap().is_empty());
+    }
+
+    #[test]
+    fn clears_a_day_a_year_or_everything() {
+        let cache = filled("clear");
+
+        assert_eq!(cache.0.clear(Some(2024), Some(1)).unwrap(), 1);
+        assert_eq!(cache.0.list_inputs().unwrap(), [(2015, 1), (2024, 2)]);
+
+        assert_eq!(cache.0.clear(Some(2015), None).unwrap(), 2);
+        assert!(!cache.0.root().join("2015").exists());
+
+        assert_eq!(cache.0.clear(None, None).unwrap(), 1);
+        assert!(cache.0.list_inputs().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+
+use regex::Regex;
+
+/// Stars earned per day, as shown on a year's calendar page. Each day links
+/// to its puzzle with a label such as `Day 3, two stars`.
+pub fn parse_stars(page: &str) -> BTreeMap<u32, u8> {
+    let label = Regex::new(r#"aria-label="Day (\d+)(?:, (one star|two stars))?""#).unwrap();
+
+    label
+        .captures_iter(page)
+        .filter_map(|c| {
+            let day = c[1].parse().ok()?;
+            let stars = match c.get(2).map(|m| m.as_str()) {
+                Some("two stars") => 2,
+                Some(_) => 1,
+                None => 0,
+            };
+            Some((day, stars))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_stars_from_day_labels() {
+        let page = r#"
+<a aria-label="Day 1, two stars" href="/2024/day/1" class="calendar-day1 calendar-verycomplete">
+<a aria-label="Day 2, one star" href="/2024/day/2" class="calendar-day2 calendar-complete">
+<a aria-label="Day 3" href="/2024/day/3" class="calendar-day3">
+<span aria-hidden="true" class="calendar-day25">
+"#;
+
+        assert_eq!(parse_stars(page), BTreeMap::from([(1, 2), (2, 1), (3, 0)]));
+    }
+}
@@ -0,0 +1,44 @@
+use std::{error::Error, fmt::Display};
+
+/// Why adventofcode.com refused a request, so callers can react to each case
+/// instead of handing an error page to a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchError {
+    /// The profile has no session token, so nothing was sent.
+    NoToken,
+    /// The session token is wrong or expired.
+    Unauthenticated,
+    /// The puzzle exists but has not unlocked yet.
+    NotUnlocked(String),
+    NotFound(String),
+    Server(u16),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoToken => write!(
+                f,
+                "No session token is set; log in to adventofcode.com and copy the `session` cookie"
+            ),
+            FetchError::Unauthenticated => write!(
+                f,
+                "The session token was not accepted; log in to adventofcode.com and copy a fresh one"
+            ),
+            FetchError::NotUnlocked(url) => write!(f, "{} has not unlocked yet", url),
+            FetchError::NotFound(url) => write!(f, "{} was not found", url),
+            FetchError::Server(status) => {
+                write!(f, "adventofcode.com is having trouble (HTTP {})", status)
+            }
+        }
+    }
+}
+
+impl FetchError {
+    /// Whether a new session token would fix this.
+    pub fn is_token(&self) -> bool {
+        matches!(self, FetchError::NoToken | FetchError::Unauthenticated)
+    }
+}
+
+impl Error for FetchError {}
@@ -0,0 +1,275 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{Read, Seek, Write},
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+use reqwest::{
+    blocking::{Client, RequestBuilder, Response},
+    header::RETRY_AFTER,
+    StatusCode,
+};
+
+use crate::conf::{self, Conf};
+
+use super::FetchError;
+
+const STAMP_FILE: &str = "last-request";
+
+/// The longest wait between retries, however many there are.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Told how long a failed request waits before it is retried.
+pub type OnRetry = Box<dyn Fn(Duration) + Send + Sync>;
+
+/// A client that identifies itself, spaces requests out across processes and
+/// retries transient failures.
+pub struct Http {
+    client: Client,
+    /// `None` when the profile has no token, which fails every request.
+    cookie: Option<String>,
+    settings: conf::Http,
+    stamp: PathBuf,
+    on_retry: OnRetry,
+}
+
+/// Turns the site's ways of saying no into a [`FetchError`]. Logged-out
+/// requests get a 400 or a "Please log in" page, or are redirected away from
+/// pages that need an account; locked puzzles answer with a 404.
+fn check(url: &str, response: Response) -> Result<String> {
+    let status = response.status();
+    let redirected = response.url().as_str() != url;
+    classify(url, status, redirected, response.text()?)
+}
+
+fn classify(url: &str, status: StatusCode, redirected: bool, body: String) -> Result<String> {
+    let error = match status {
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            FetchError::Unauthenticated
+        }
+        StatusCode::NOT_FOUND if body.contains("before it unlocks") => {
+            FetchError::NotUnlocked(url.to_string())
+        }
+        StatusCode::NOT_FOUND => FetchError::NotFound(url.to_string()),
+        s if s.is_server_error() => FetchError::Server(s.as_u16()),
+        s if !s.is_success() => return Err(anyhow!("Unexpected HTTP {} from {}", s, url)),
+        _ if redirected || body.contains("Please log in") => FetchError::Unauthenticated,
+        _ => return Ok(body),
+    };
+
+    Err(error.into())
+}
+
+/// The wait before retry number `attempt`, counting from zero: `base_ms`
+/// doubled each time, up to [`MAX_BACKOFF`].
+fn backoff(base_ms: u64, attempt: u32) -> Duration {
+    let factor = 1u64.checked_shl(attempt).unwrap_or(u64::MAX);
+    Duration::from_millis(base_ms.saturating_mul(factor)).min(MAX_BACKOFF)
+}
+
+/// The wait a `Retry-After` header asks for. Only the delay in seconds is
+/// understood, not the HTTP-date form.
+fn retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+impl Http {
+    pub fn new(token: Option<&str>, settings: conf::Http) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent(&settings.user_agent)
+            .connect_timeout(Duration::from_secs(settings.connect_timeout_secs))
+            .timeout(Duration::from_secs(settings.timeout_secs))
+            .build()?;
+        let stamp = Conf::dir().map_err(|e| anyhow!("{}", e))?.join(STAMP_FILE);
+
+        Ok(Self {
+            client,
+            cookie: token.map(|token| format!("session={}", token)),
+            settings,
+            stamp,
+            on_retry: Box::new(|wait| eprintln!("Request failed, retrying in {:.1?}...", wait)),
+        })
+    }
+
+    /// Replaces the message printed before each retry, for callers that
+    /// cannot print to the terminal.
+    pub fn on_retry(&mut self, on_retry: OnRetry) {
+        self.on_retry = on_retry;
+    }
+
+    pub fn get(&self, url: &str) -> Result<String> {
+        check(
+            url,
+            self.send(|| self.client.get(url), true, self.settings.retries)?,
+        )
+    }
+
+    /// Gets a page without retrying, for things that are not worth waiting
+    /// for when the site cannot be reached.
+    pub fn get_once(&self, url: &str) -> Result<String> {
+        check(url, self.send(|| self.client.get(url), true, 0)?)
+    }
+
+    /// Posts a form. Only failures to connect are retried, since anything
+    /// else may have reached the site already.
+    pub fn post_form(&self, url: &str, form: &[(&str, String)]) -> Result<String> {
+        check(
+            url,
+            self.send(
+                || self.client.post(url).form(form),
+                false,
+                self.settings.retries,
+            )?,
+        )
+    }
+
+    fn send(
+        &self,
+        request: impl Fn() -> RequestBuilder,
+        idempotent: bool,
+        retries: u32,
+    ) -> Result<Response> {
+        let cookie = self.cookie.as_ref().ok_or(FetchError::NoToken)?;
+        let mut attempt = 0;
+
+        loop {
+            self.throttle()?;
+            let result = request().header("Cookie", cookie).send();
+
+            let transient = match &result {
+                Ok(response) => {
+                    idempotent
+                        && (response.status().is_server_error()
+                            || response.status() == StatusCode::TOO_MANY_REQUESTS)
+                }
+                Err(e) => e.is_connect() || (idempotent && e.is_timeout()),
+            };
+
+            if !transient || attempt >= retries {
+                return Ok(result?);
+            }
+
+            // A rate-limited response may say how long to wait.
+            let wait = result
+                .ok()
+                .filter(|r| r.status() == StatusCode::TOO_MANY_REQUESTS)
+                .and_then(|r| retry_after(r.headers().get(RETRY_AFTER)?.to_str().ok()?))
+                .unwrap_or_else(|| backoff(self.settings.backoff_ms, attempt));
+            (self.on_retry)(wait);
+            thread::sleep(wait);
+            attempt += 1;
+        }
+    }
+
+    /// Waits until the configured interval has passed since the last request
+    /// made by any process, then records this one. The stamp stays locked
+    /// throughout, so other processes queue up behind it instead of reading
+    /// the same time and sending at once.
+    fn throttle(&self) -> Result<()> {
+        let mut stamp = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.stamp)?;
+        stamp.lock()?;
+
+        let last = read_stamp(&mut stamp).unwrap_or(0);
+        let next = last.saturating_add(self.settings.min_interval_ms);
+        let now = now_ms();
+
+        if next > now {
+            thread::sleep(Duration::from_millis(next - now));
+        }
+
+        stamp.set_len(0)?;
+        stamp.rewind()?;
+        stamp.write_all(now_ms().to_string().as_bytes())?;
+        Ok(())
+    }
+}
+
+fn read_stamp(file: &mut File) -> Option<u64> {
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).ok()?;
+    contents.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_a_limit() {
+        assert_eq!(backoff(1000, 0), Duration::from_secs(1));
+        assert_eq!(backoff(1000, 2), Duration::from_secs(4));
+        assert_eq!(backoff(1000, 6), MAX_BACKOFF);
+        assert_eq!(backoff(1000, 64), MAX_BACKOFF);
+    }
+
+    const URL: &str = "https://adventofcode.com/2024/day/1/input";
+
+    fn error(status: StatusCode, redirected: bool, body: &str) -> FetchError {
+        classify(URL, status, redirected, body.to_string())
+            .unwrap_err()
+            .downcast()
+            .unwrap()
+    }
+
+    #[test]
+    fn logged_out_inputs_are_unauthenticated() {
+        let body = "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+        assert_eq!(
+            error(StatusCode::BAD_REQUEST, false, body),
+            FetchError::Unauthenticated
+        );
+        assert_eq!(
+            error(StatusCode::OK, false, body),
+            FetchError::Unauthenticated
+        );
+        assert_eq!(
+            error(StatusCode::OK, true, "<html>"),
+            FetchError::Unauthenticated
+        );
+    }
+
+    #[test]
+    fn locked_days_are_told_apart_from_missing_ones() {
+        let body = "Please don't repeatedly request this endpoint before it unlocks!";
+        assert_eq!(
+            error(StatusCode::NOT_FOUND, false, body),
+            FetchError::NotUnlocked(URL.to_string())
+        );
+        assert_eq!(
+            error(StatusCode::NOT_FOUND, false, "404 Not Found"),
+            FetchError::NotFound(URL.to_string())
+        );
+    }
+
+    #[test]
+    fn retry_after_is_read_in_seconds() {
+        assert_eq!(retry_after(" 120 "), Some(Duration::from_secs(120)));
+        assert_eq!(retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+
+    #[test]
+    fn server_errors_and_inputs() {
+        assert_eq!(
+            error(StatusCode::BAD_GATEWAY, false, ""),
+            FetchError::Server(502)
+        );
+        assert_eq!(
+            classify(URL, StatusCode::OK, false, "1 2 3\n".to_string()).unwrap(),
+            "1 2 3\n"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::{fmt::Display, path::PathBuf};
+
+/// Where a run reads its puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Source {
+    /// The account's own input, served from the cache or adventofcode.com.
+    #[default]
+    Network,
+    File(PathBuf),
+    Stdin,
+    /// The nth code block (1-based) of the puzzle description.
+    Example(usize),
+}
+
+impl Source {
+    pub fn is_network(&self) -> bool {
+        matches!(self, Source::Network)
+    }
+
+    /// Whether this is a single input rather than one for each day, so it
+    /// can only be given to one day at a time.
+    pub fn is_single(&self) -> bool {
+        matches!(self, Source::File(_) | Source::Stdin)
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Network => write!(f, "puzzle input"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Example(n) => write!(f, "example {}", n),
+        }
+    }
+}
+
+/// Pulls the code blocks out of a description produced by `puzzle::to_markdown`.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        match (line == "```", current.as_mut()) {
+            (true, None) => current = Some(Vec::new()),
+            (true, Some(lines)) => {
+                blocks.push(lines.join("\n"));
+                current = None;
+            }
+            (false, Some(lines)) => lines.push(line),
+            (false, None) => {}
+        }
+    }
+
+    blocks
+}
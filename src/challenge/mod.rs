@@ -1,30 +1,193 @@
-use anyhow::Result;
-use reqwest::blocking::Client;
+use std::{collections::BTreeMap, fs, io::Read, time::Duration};
+
+use anyhow::{anyhow, Result};
+
+use crate::conf;
+
+mod cache;
+mod calendar;
+mod error;
+mod http;
+mod input;
+mod puzzle;
+mod submit;
+
+pub use cache::Cache;
+pub use error::FetchError;
+pub use input::{code_blocks, Source};
+pub use submit::Verdict;
 
 fn format_url(year: i32, day: u32) -> String {
     format!("https://adventofcode.com/{}/day/{}/input", year, day)
 }
 
+fn format_puzzle_url(year: i32, day: u32) -> String {
+    format!("https://adventofcode.com/{}/day/{}", year, day)
+}
+
+fn format_calendar_url(year: i32) -> String {
+    format!("https://adventofcode.com/{}", year)
+}
+
+fn format_leaderboard_url(year: i32, id: u64) -> String {
+    format!(
+        "https://adventofcode.com/{}/leaderboard/private/view/{}.json",
+        year, id
+    )
+}
+
+/// The site asks for private leaderboards to be fetched at most this often.
+const LEADERBOARD_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Stars only change through answers submitted here or elsewhere, so an hour
+/// is fresh enough; answers accepted here clear the cache right away.
+const STARS_TTL: Duration = Duration::from_secs(60 * 60);
+
+fn format_answer_url(year: i32, day: u32) -> String {
+    format!("https://adventofcode.com/{}/day/{}/answer", year, day)
+}
+
 pub struct Fetcher {
-    client: Client,
-    token: String,
+    http: http::Http,
+    account: String,
+    cache: Cache,
+    refresh: bool,
+    source: Source,
 }
 
 impl Fetcher {
-    pub fn new(token: String) -> Self {
-        let client = Client::new();
+    pub fn new(account: conf::Account) -> Result<Self> {
+        Ok(Self {
+            http: http::Http::new(account.token.as_deref(), account.http)?,
+            cache: Cache::new(&account.cache_dir, &account.profile),
+            account: account.profile,
+            refresh: false,
+            source: Source::default(),
+        })
+    }
+
+    /// Bypass cached inputs and fetch them again from the site.
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    /// Reports retried requests with `on_retry`, which is given the wait,
+    /// instead of printing to stderr.
+    pub fn on_retry(mut self, on_retry: impl Fn(Duration) + Send + Sync + 'static) -> Self {
+        self.http.on_retry(Box::new(on_retry));
+        self
+    }
+
+    /// Read puzzle inputs from somewhere other than the account's own input.
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn input_source(&self) -> &Source {
+        &self.source
+    }
+
+    pub fn account(&self) -> &str {
+        &self.account
+    }
 
-        Self { client, token }
+    pub fn cache(&self) -> &Cache {
+        &self.cache
     }
 
     pub fn fetch_challenge(&self, year: i32, day: u32) -> Result<String> {
-        Ok(self
-            .client
-            .get(format_url(year, day))
-            .header("Cookie", format!("session={}", self.token))
-            .send()?
-            .text()?
-            .trim()
-            .to_string())
+        match &self.source {
+            Source::Network => self.fetch_input(year, day),
+            Source::File(path) => Ok(fs::read_to_string(path)?.trim().to_string()),
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input.trim().to_string())
+            }
+            Source::Example(n) => input::code_blocks(&self.fetch_puzzle(year, day)?)
+                .get(n.saturating_sub(1))
+                .map(|example| example.trim().to_string())
+                .ok_or_else(|| anyhow!("Puzzle {} day {} has no example {}", year, day, n)),
+        }
+    }
+
+    fn fetch_input(&self, year: i32, day: u32) -> Result<String> {
+        if !self.refresh {
+            if let Some(input) = self.cache.read_input(year, day) {
+                return Ok(input);
+            }
+        }
+
+        let input = self.http.get(&format_url(year, day))?.trim().to_string();
+
+        self.cache.write_input(year, day, &input)?;
+
+        Ok(input)
+    }
+
+    /// Fetches the puzzle description as Markdown, including part two once
+    /// it has been unlocked.
+    pub fn fetch_puzzle(&self, year: i32, day: u32) -> Result<String> {
+        if !self.refresh {
+            if let Some(markdown) = self.cache.read_puzzle(year, day) {
+                return Ok(markdown);
+            }
+        }
+
+        let page = self.http.get(&format_puzzle_url(year, day))?;
+
+        let markdown = puzzle::to_markdown(&page)
+            .ok_or_else(|| anyhow!("No puzzle description found for {} day {}", year, day))?;
+
+        self.cache.write_puzzle(year, day, &markdown)?;
+
+        Ok(markdown)
+    }
+
+    /// Fetches how many stars the account has for each day of a year. Stars
+    /// are only shown as progress, so a failed request is not retried.
+    pub fn fetch_stars(&self, year: i32) -> Result<BTreeMap<u32, u8>> {
+        if !self.refresh {
+            if let Some(stars) = self.cache.read_stars(year, STARS_TTL) {
+                return Ok(stars);
+            }
+        }
+
+        let page = self.http.get_once(&format_calendar_url(year))?;
+        let stars = calendar::parse_stars(&page);
+        self.cache.write_stars(year, &stars)?;
+
+        Ok(stars)
+    }
+
+    /// Fetches a private leaderboard's JSON. A copy younger than fifteen
+    /// minutes is always reused, even when refreshing, as the site requests.
+    pub fn fetch_leaderboard(&self, year: i32, id: u64) -> Result<String> {
+        if let Some(json) = self.cache.read_leaderboard(year, id, LEADERBOARD_TTL) {
+            return Ok(json);
+        }
+
+        let json = self.http.get(&format_leaderboard_url(year, id))?;
+
+        self.cache.write_leaderboard(year, id, &json)?;
+
+        Ok(json)
+    }
+
+    pub fn submit_answer(&self, year: i32, day: u32, part: u8, answer: &str) -> Result<Verdict> {
+        let page = self.http.post_form(
+            &format_answer_url(year, day),
+            &[("level", part.to_string()), ("answer", answer.to_string())],
+        )?;
+
+        let verdict = Verdict::parse(&page);
+        if verdict == Verdict::Correct {
+            self.cache.remove_puzzle(year, day)?;
+            self.cache.remove_stars(year)?;
+        }
+
+        Ok(verdict)
     }
 }
@@ -0,0 +1,97 @@
+use regex::{Captures, Regex};
+
+/// Converts the `<article class="day-desc">` sections of a puzzle page into
+/// Markdown. Part two only appears once part one has been solved.
+pub fn to_markdown(page: &str) -> Option<String> {
+    let articles = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+
+    let parts = articles
+        .captures_iter(page)
+        .map(|c| convert(c.get(1).unwrap().as_str()))
+        .collect::<Vec<_>>();
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    Some(parts.join("\n\n") + "\n")
+}
+
+fn convert(html: &str) -> String {
+    let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let code = Regex::new(r"(?s)<code>(.*?)</code>").unwrap();
+    let em = Regex::new(r"(?s)<em[^>]*>(.*?)</em>").unwrap();
+    let link = Regex::new(r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#).unwrap();
+    let heading = Regex::new(r"(?s)<h2[^>]*>(.*?)</h2>").unwrap();
+    let item = Regex::new(r"(?s)<li>(.*?)</li>\s*").unwrap();
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    let blank_lines = Regex::new(r"\n{3,}").unwrap();
+
+    let md = pre.replace_all(html, |c: &Captures| {
+        format!(
+            "\n\n```\n{}\n```\n\n",
+            tag.replace_all(&c[1], "").trim_end()
+        )
+    });
+    let md = code.replace_all(&md, |c: &Captures| {
+        format!("`{}`", tag.replace_all(&c[1], ""))
+    });
+    let md = em.replace_all(&md, "*$1*");
+    let md = link.replace_all(&md, |c: &Captures| {
+        let href = match &c[1] {
+            h if h.starts_with('/') => format!("https://adventofcode.com{}", h),
+            h => h.to_string(),
+        };
+        format!("[{}]({})", &c[2], href)
+    });
+    let md = heading.replace_all(&md, "## $1\n\n");
+    let md = item.replace_all(&md, "- $1\n");
+    let md = md
+        .replace("<p>", "")
+        .replace("</p>", "\n\n")
+        .replace("<ul>", "\n")
+        .replace("</ul>", "\n");
+    let md = tag.replace_all(&md, "");
+    let md = decode_entities(&md);
+
+    blank_lines.replace_all(md.trim(), "\n\n").to_string()
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_a_recorded_puzzle() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/puzzle/2024-01.html");
+        let markdown = to_markdown(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let lines: Vec<_> = markdown.lines().collect();
+
+        assert_eq!(lines[0], "## --- Day 1: Historian Hysteria ---");
+        assert!(lines[2].starts_with("The *Chief Historian* is always present"));
+        assert!(lines[4].ends_with("before Santa takes off on December 25th."));
+        assert!(lines[6].contains("[Advent calendar](https://adventofcode.com/2024);"));
+        assert_eq!(lines[8..12], ["For example:", "", "```", "3   4"]);
+        assert!(lines[19].contains("if you pair up a `9` with a `3`"));
+        assert_eq!(lines[22], "- Lists & pairs are compared with `a < b`.");
+        assert_eq!(
+            lines.last(),
+            Some(&"Your actual left and right lists contain many location IDs. *What is the total distance between your lists?*")
+        );
+
+        assert_eq!(
+            crate::challenge::code_blocks(&markdown),
+            ["3   4\n4   3\n2   5\n1   3\n3   9\n3   3"]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::{fmt::Display, time::Duration};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Outcome of posting an answer, parsed from the `<article>` of the response page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    pub fn parse(page: &str) -> Self {
+        let text = article_text(page);
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited(parse_wait(&text))
+        } else if text.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::AlreadySolved)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::TooHigh => write!(f, "That's not the right answer; it is too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer; it is too low."),
+            Verdict::RateLimited(wait) => write!(
+                f,
+                "Answer submitted too recently; wait {}m {}s before trying again.",
+                wait.as_secs() / 60,
+                wait.as_secs() % 60
+            ),
+            Verdict::AlreadySolved => write!(f, "This part has already been solved."),
+            Verdict::Unknown(text) => write!(f, "Unrecognized response: {}", text),
+        }
+    }
+}
+
+fn article_text(page: &str) -> String {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>")
+        .unwrap()
+        .captures(page)
+        .map(|c| c.get(1).unwrap().as_str())
+        .unwrap_or(page);
+
+    let text = Regex::new(r"<[^>]*>").unwrap().replace_all(article, "");
+    Regex::new(r"\s+")
+        .unwrap()
+        .replace_all(&text, " ")
+        .trim()
+        .to_string()
+}
+
+fn parse_wait(text: &str) -> Duration {
+    let rgx = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+
+    rgx.captures(text)
+        .map(|c| {
+            let minutes = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+            let seconds = c.get(2).unwrap().as_str().parse::<u64>().unwrap();
+            Duration::from_secs(minutes * 60 + seconds)
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    const STUCK: &str = "If you're stuck, make sure you're using the full input data; there \
+        are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can \
+        ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" \
+        target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. \
+        <a href=\"/2024/day/1\">[Return to Day 1]</a>";
+
+    #[test]
+    fn parses_right_and_wrong_answers() {
+        let right = page(
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> \
+             closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to \
+             Part Two]</a>",
+        );
+        assert_eq!(Verdict::parse(&right), Verdict::Correct);
+
+        let wrong = page(&format!("That's not the right answer.  {}", STUCK));
+        assert_eq!(Verdict::parse(&wrong), Verdict::Wrong);
+
+        let high = page(&format!(
+            "That's not the right answer; your answer is too high.  {}",
+            STUCK
+        ));
+        assert_eq!(Verdict::parse(&high), Verdict::TooHigh);
+
+        let low = page(&format!(
+            "That's not the right answer; your answer is too low.  {}",
+            STUCK
+        ));
+        assert_eq!(Verdict::parse(&low), Verdict::TooLow);
+    }
+
+    #[test]
+    fn parses_the_wait_after_answering_too_recently() {
+        let recently = |left: &str| {
+            page(&format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {} left to wait. <a href=\"/2024/day/1\">[Return \
+                 to Day 1]</a>",
+                left
+            ))
+        };
+
+        assert_eq!(
+            Verdict::parse(&recently("1m 2s")),
+            Verdict::RateLimited(Duration::from_secs(62))
+        );
+        assert_eq!(
+            Verdict::parse(&recently("34s")),
+            Verdict::RateLimited(Duration::from_secs(34))
+        );
+    }
+
+    #[test]
+    fn parses_an_already_completed_level() {
+        let already = page(
+            "You don't seem to be solving the right level.  Did you already complete it? \
+             <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+        );
+
+        assert_eq!(Verdict::parse(&already), Verdict::AlreadySolved);
+        assert!(Verdict::parse(&already).is_accepted());
+    }
+}
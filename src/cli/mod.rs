@@ -0,0 +1,207 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::{challenge::Source, runner::Format};
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", version, about = "Advent of Code solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// Use the token and settings of a profile from the config file
+    #[arg(long, global = true, env = "AOC_PROFILE")]
+    pub profile: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    /// Read the puzzle input from a file, or from stdin with `-`
+    #[arg(short, long, global = true, conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    /// Use the nth example from the puzzle description as input
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(u8).range(1..))]
+    pub example: Option<u8>,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> Source {
+        match (&self.input, self.example) {
+            (Some(path), _) if path.as_os_str() == "-" => Source::Stdin,
+            (Some(path), _) => Source::File(path.clone()),
+            (None, Some(n)) => Source::Example(n as usize),
+            (None, None) => Source::Network,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run the solution for a year and day without the interactive menu
+    Run(RunArgs),
+    /// Submit an answer to adventofcode.com
+    Submit(SubmitArgs),
+    /// Print the puzzle description as Markdown
+    Show(ShowArgs),
+    /// Time repeated runs of a day or year, optionally against a saved baseline
+    Bench(BenchArgs),
+    /// Show a private leaderboard's standings, or each member's times for a day
+    Leaderboard(LeaderboardArgs),
+    /// Create the module, input cache and example fixture for a new day
+    New(NewArgs),
+    /// Count down to a puzzle's unlock, then fetch its input and description
+    Wait(WaitArgs),
+    /// Show, change or locate the config file
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Inspect or clear cached puzzle inputs
+    #[command(subcommand)]
+    Cache(CacheCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    /// Year of the challenge, e.g. 2024; defaults to `year` in the config
+    #[arg(short, long)]
+    pub year: Option<i32>,
+
+    /// Day of the challenge, 1 through 25, or through 12 from 2025 on
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    pub day: Option<i32>,
+
+    /// Only print the answer for this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Run every day registered for the year, or for every year if none is given
+    #[arg(short, long)]
+    pub all: bool,
+
+    /// Fetch the input again instead of using the cached copy
+    #[arg(long)]
+    pub refresh: bool,
+
+    /// Submit the answer for the selected part after solving
+    #[arg(short, long, requires = "part", conflicts_with_all = ["format", "all"])]
+    pub submit: bool,
+
+    /// Print results as text, or as JSON, YAML or CSV records; defaults to
+    /// `format` in the config
+    #[arg(short, long, value_enum)]
+    pub format: Option<Format>,
+}
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    #[arg(short, long)]
+    pub year: Option<i32>,
+
+    #[arg(short, long)]
+    pub day: i32,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// The answer to submit
+    pub answer: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ShowArgs {
+    #[arg(short, long)]
+    pub year: Option<i32>,
+
+    #[arg(short, long)]
+    pub day: i32,
+
+    /// Fetch the description again instead of using the cached copy
+    #[arg(long)]
+    pub refresh: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    #[arg(short, long)]
+    pub year: Option<i32>,
+
+    /// Only benchmark this day instead of the whole year
+    #[arg(short, long)]
+    pub day: Option<i32>,
+
+    /// Number of runs per day
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+    pub runs: u16,
+
+    /// Write the median timings to a baseline file
+    #[arg(long)]
+    pub save: Option<PathBuf>,
+
+    /// Compare the median timings against a baseline file
+    #[arg(long)]
+    pub compare: Option<PathBuf>,
+
+    /// Slowdown in percent over the baseline reported as a regression
+    #[arg(long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+#[derive(Args, Debug)]
+pub struct LeaderboardArgs {
+    #[arg(short, long)]
+    pub year: Option<i32>,
+
+    /// The leaderboard's id, the number at the end of its URL
+    #[arg(long)]
+    pub id: u64,
+
+    /// Show star times and part 1 to part 2 deltas for this day
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: Option<u32>,
+}
+
+#[derive(Args, Debug)]
+pub struct NewArgs {
+    #[arg(short, long)]
+    pub year: Option<i32>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct WaitArgs {
+    #[arg(short, long)]
+    pub year: Option<i32>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheCommand {
+    /// List cached inputs
+    List,
+    /// Remove cached inputs, optionally limited to a year or day
+    Clear {
+        #[arg(short, long)]
+        year: Option<i32>,
+
+        #[arg(short, long, requires = "year")]
+        day: Option<u32>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Print the settings in effect, with tokens shortened
+    Show,
+    /// Change a setting, e.g. `aoc config set http.timeout_secs 60`
+    Set { key: String, value: String },
+    /// Print the location of the config file
+    Path,
+}
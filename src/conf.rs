@@ -1,59 +1,571 @@
-use std::{error::Error, fs, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fs,
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
 
 use confique::Config;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal,
+};
 use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+
+use crate::runner::Format;
 
 #[derive(Config, Debug, Serialize, Deserialize)]
 pub struct Conf {
+    /// The session cookie. Takes precedence over `token_file`.
     #[config(env = "TOKEN")]
-    pub token: String,
+    pub token: Option<String>,
+
+    /// A file holding the session cookie, so this file can be shared.
+    #[config(env = "AOC_TOKEN_FILE")]
+    pub token_file: Option<PathBuf>,
+
+    /// Year used by commands run without `--year`.
+    #[config(env = "AOC_YEAR")]
+    pub year: Option<i32>,
+
+    /// Where inputs and puzzles are cached, instead of `cache` in the config
+    /// directory.
+    #[config(env = "AOC_CACHE_DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Output of `aoc run` when no `--format` is given.
+    #[config(default = "text", env = "AOC_FORMAT")]
+    pub format: Format,
+
+    /// Turns off colors. A non-empty `NO_COLOR` does the same.
+    #[config(default = false, env = "AOC_NO_COLOR")]
+    pub no_color: bool,
+
+    #[config(nested)]
+    pub http: Http,
+
+    #[config(nested)]
+    pub theme: Theme,
+
+    /// Other accounts, selected with `--profile` or `AOC_PROFILE`. The
+    /// top-level token is the `default` profile.
+    #[config(default = {})]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_file: Option<PathBuf>,
+
+    /// Overrides for any of the top-level `http` settings.
+    #[serde(default, skip_serializing_if = "serde_yaml::Mapping::is_empty")]
+    pub http: serde_yaml::Mapping,
+}
+
+/// The token and settings of the selected profile. Cached inputs and answer
+/// history are stored per profile, so each account stays separate.
+pub struct Account {
+    /// The profile's name, which keys its cache and history. Tokens expire
+    /// and get replaced, so they would lose both.
+    pub profile: String,
+    /// Missing until one is entered, which only matters once a request is
+    /// made.
+    pub token: Option<String>,
+    pub http: Http,
+    pub cache_dir: PathBuf,
+}
+
+/// How requests to adventofcode.com are made. The site asks automated tools
+/// to identify themselves and to go easy on its servers.
+#[derive(Config, Debug, Clone, Serialize, Deserialize)]
+pub struct Http {
+    /// Sent with every request; include a way to contact you.
+    #[config(
+        default = "github.com/theZMC/advent-of-code-rs",
+        env = "AOC_USER_AGENT"
+    )]
+    pub user_agent: String,
+
+    /// Minimum time between two requests, shared by every running process.
+    #[config(default = 1000, env = "AOC_MIN_INTERVAL_MS")]
+    pub min_interval_ms: u64,
+
+    /// How many times a request that failed for a transient reason is retried.
+    #[config(default = 3, env = "AOC_RETRIES")]
+    pub retries: u32,
+
+    /// Wait before the first retry, doubled for each one after it.
+    #[config(default = 1000, env = "AOC_BACKOFF_MS")]
+    pub backoff_ms: u64,
+
+    /// Limit on connecting to the site, within the overall timeout.
+    #[config(default = 10, env = "AOC_CONNECT_TIMEOUT_SECS")]
+    pub connect_timeout_secs: u64,
+
+    /// Limit on a whole request, from connecting to reading the response.
+    #[config(default = 30, env = "AOC_TIMEOUT_SECS")]
+    pub timeout_secs: u64,
+}
+
+/// Menu colors, by name: black, grey, white, red, green, yellow, blue,
+/// magenta or cyan, or any of these but white and grey prefixed with `dark_`.
+#[derive(Config, Debug, Clone, Serialize, Deserialize)]
+pub struct Theme {
+    #[config(default = "black", env = "AOC_THEME_SELECTED_FG")]
+    pub selected_fg: String,
+
+    #[config(default = "grey", env = "AOC_THEME_SELECTED_BG")]
+    pub selected_bg: String,
+
+    /// Days without a solution and hints.
+    #[config(default = "dark_grey", env = "AOC_THEME_DIM")]
+    pub dim: String,
+
+    #[config(default = "yellow", env = "AOC_THEME_TWO_STARS")]
+    pub two_stars: String,
+
+    #[config(default = "blue", env = "AOC_THEME_ONE_STAR")]
+    pub one_star: String,
+}
+
+/// Where the menu was left, so it reopens there. Kept in `state.yaml` next to
+/// the config, so the menu never rewrites the user's own file.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MenuState {
+    pub last_year: Option<i32>,
+    pub last_day: Option<i32>,
+}
+
+impl MenuState {
+    /// The saved state, or none if it is missing or unreadable.
+    pub fn load() -> Self {
+        Self::path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|yaml| serde_yaml::from_str(&yaml).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        fs::write(Self::path()?, serde_yaml::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn path() -> Result<PathBuf, Box<dyn Error>> {
+        Ok(Conf::dir()?.join("state.yaml"))
+    }
 }
 
 impl Conf {
-    pub fn load_or_create() -> Result<Self, Box<dyn Error>> {
-        let config_path = Self::get_path()?;
-        let mut builder = Self::builder().env();
-        if config_path.exists() {
-            builder = builder.file(config_path);
-        }
-        let conf = match builder.load() {
-            Ok(c) => c,
-            Err(_) => {
-                let token = Self::prompt_for_token()?;
-                let new_conf = Self { token };
-                new_conf.save_to_file()?;
-                return Ok(new_conf);
-            }
+    /// Loads the config, asking for the selected profile's token first if it
+    /// has none and `needs_token` is set.
+    pub fn load_or_create(
+        profile: Option<&str>,
+        needs_token: bool,
+    ) -> Result<Self, Box<dyn Error>> {
+        let path = Self::get_path()?;
+        warn_if_exposed(&path);
+
+        let conf = Self::load(&path)?;
+        let name = profile.unwrap_or(DEFAULT_PROFILE);
+        let (key, token, file) = match conf.profiles.get(name) {
+            _ if name == DEFAULT_PROFILE => ("token".to_string(), &conf.token, &conf.token_file),
+            Some(named) => (
+                format!("profiles.{}.token", name),
+                &named.token,
+                &named.token_file,
+            ),
+            // A missing profile is reported by `account`.
+            None => return Ok(conf),
+        };
+        // An empty token, as older versions saved, counts as none.
+        let token = token.as_deref().is_some_and(|t| !t.trim().is_empty());
+        if !needs_token || token || file.is_some() {
+            return Ok(conf);
+        }
+
+        let token = Self::prompt_for_token()?;
+        Self::set(&path, &key, Value::String(token))?;
+        Self::saved(&path);
+        Self::load(&path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::builder().env().file(path).load()?)
+    }
+
+    /// Whether output may be colored, per `no_color` and `NO_COLOR`.
+    pub fn color(&self) -> bool {
+        !self.no_color && env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+    }
+
+    pub fn account(&self, profile: Option<&str>) -> Result<Account, Box<dyn Error>> {
+        let cache_dir = match &self.cache_dir {
+            Some(dir) => dir.clone(),
+            None => Self::dir()?.join("cache"),
+        };
+
+        let name = profile.unwrap_or(DEFAULT_PROFILE);
+        if name == DEFAULT_PROFILE {
+            return Ok(Account {
+                profile: name.to_string(),
+                token: read_token(&self.token, &self.token_file)?,
+                http: self.http.clone(),
+                cache_dir,
+            });
+        }
+
+        let profile = self.profiles.get(name).ok_or_else(|| {
+            format!(
+                "No profile named {:?} in {}",
+                name,
+                Self::get_path().unwrap_or_default().display()
+            )
+        })?;
+
+        Ok(Account {
+            profile: name.to_string(),
+            token: read_token(&profile.token, &profile.token_file)?,
+            http: self.profile_http(name, profile)?,
+            cache_dir,
+        })
+    }
+
+    /// The top-level `http` settings with a profile's overrides applied.
+    fn profile_http(&self, name: &str, profile: &Profile) -> Result<Http, Box<dyn Error>> {
+        let mut http = serde_yaml::to_value(&self.http)?;
+        if let Value::Mapping(settings) = &mut http {
+            settings.extend(profile.http.clone());
+        }
+
+        serde_yaml::from_value(http)
+            .map_err(|e| format!("Invalid profiles.{}.http: {}", name, e).into())
+    }
+
+    /// Checks what loading alone does not: profile overrides and theme colors.
+    fn validate(&self) -> Result<(), Box<dyn Error>> {
+        for (name, profile) in &self.profiles {
+            self.profile_http(name, profile)?;
+        }
+        crate::menu::Palette::new(&self.theme, true)?;
+        Ok(())
+    }
+
+    /// Asks for a new session token for a profile and saves it, for when the
+    /// site has rejected the current one.
+    pub fn replace_token(&mut self, profile: &str) -> Result<(), Box<dyn Error>> {
+        let token = Self::prompt_for_token()?;
+        let (key, inline, file) = match self.profiles.get_mut(profile) {
+            Some(named) => (
+                format!("profiles.{}.token", profile),
+                &mut named.token,
+                &named.token_file,
+            ),
+            None => ("token".to_string(), &mut self.token, &self.token_file),
+        };
+
+        // A token kept in its own file is replaced there.
+        if let (None, Some(file)) = (&inline, file) {
+            let file = expand_home(file);
+            write_private(&file, &token)?;
+            Self::saved(&file);
+            return Ok(());
+        }
+
+        *inline = Some(token.clone());
+        let path = Self::get_path()?;
+        Self::set(&path, &key, Value::String(token))?;
+        Self::saved(&path);
+        Ok(())
+    }
+
+    /// The settings in effect, with tokens shortened so they can be shared.
+    pub fn show(&self) -> Result<String, Box<dyn Error>> {
+        let mut value = serde_yaml::to_value(self)?;
+        mask(&mut value);
+        Ok(serde_yaml::to_string(&value)?)
+    }
+
+    /// Sets a dotted `key` such as `http.timeout_secs` in the config file.
+    /// The value is read as YAML, so numbers and booleans keep their type.
+    /// The change is only kept if the file still loads afterwards.
+    pub fn set_value(key: &str, value: &str) -> Result<(), Box<dyn Error>> {
+        let path = Self::get_path()?;
+        let current = serde_yaml::to_value(Self::load(&path)?)?;
+        // Profiles are not in the loaded config until they are set, so their
+        // keys are checked against the settings a profile can hold.
+        let known = match key.split('.').collect::<Vec<_>>().as_slice() {
+            ["profiles", _, "token" | "token_file"] => true,
+            ["profiles", _, "http", setting] => lookup(&current, &["http", setting]).is_some(),
+            ["profiles", ..] => false,
+            parts => lookup(&current, parts).is_some(),
         };
+        if !known {
+            return Err(format!("Unknown setting {:?}", key).into());
+        }
+
+        let previous = fs::read_to_string(&path).ok();
+        Self::set(&path, key, serde_yaml::from_str(value)?)?;
+
+        if let Err(e) = Self::load(&path).and_then(|conf| conf.validate()) {
+            match previous {
+                Some(previous) => fs::write(&path, previous)?,
+                None => fs::remove_file(&path)?,
+            }
+            return Err(format!("Invalid value for {}: {}", key, e).into());
+        }
+
+        Self::saved(&path);
+        Ok(())
+    }
+
+    fn read_file(path: &Path) -> Result<Value, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(yaml) if !yaml.trim().is_empty() => Ok(serde_yaml::from_str(&yaml)?),
+            _ => Ok(Value::Mapping(Mapping::new())),
+        }
+    }
+
+    /// Writes a single dotted key, leaving the rest of the file as it is.
+    fn set(path: &Path, key: &str, value: Value) -> Result<(), Box<dyn Error>> {
+        let mut file = Self::read_file(path)?;
+        let mut node = &mut file;
+
+        for part in key.split('.') {
+            if !node.is_mapping() {
+                *node = Value::Mapping(Mapping::new());
+            }
+            node = node
+                .as_mapping_mut()
+                .unwrap()
+                .entry(Value::String(part.to_string()))
+                .or_insert(Value::Null);
+        }
+        *node = value;
+
+        write_private(path, &serde_yaml::to_string(&file)?)?;
+        Ok(())
+    }
 
-        Ok(conf)
+    fn saved(path: &Path) {
+        println!("Config saved to file: {:?}", path.to_string_lossy());
     }
 
-    fn get_path() -> Result<PathBuf, Box<dyn Error>> {
+    pub fn dir() -> Result<PathBuf, Box<dyn Error>> {
         let mut config_dir = dirs::config_dir().ok_or("Could not find config directory")?;
         config_dir.push("advent-of-code");
         fs::create_dir_all(&config_dir)?;
-        config_dir.push("config.yaml");
         Ok(config_dir)
     }
 
+    pub fn get_path() -> Result<PathBuf, Box<dyn Error>> {
+        let mut config_path = Self::dir()?;
+        config_path.push("config.yaml");
+        Ok(config_path)
+    }
+
+    /// Reads the token without echoing it when run in a terminal. An empty
+    /// token is refused, since saving it would stop the question being asked.
     fn prompt_for_token() -> Result<String, Box<dyn Error>> {
         println!("Please enter your Advent of Code session token:");
+        let token = Self::read_secret()?;
+        match token.trim() {
+            "" => Err("No session token was entered".into()),
+            token => Ok(token.to_string()),
+        }
+    }
+
+    fn read_secret() -> Result<String, Box<dyn Error>> {
         let mut token = String::new();
-        std::io::stdin().read_line(&mut token)?;
-        Ok(token.trim().to_string())
+
+        if !io::stdin().is_terminal() {
+            io::stdin().read_line(&mut token)?;
+            return Ok(token);
+        }
+
+        let raw_mode = RawMode::enable()?;
+        let cancelled = loop {
+            if let Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read()?
+            {
+                match code {
+                    KeyCode::Enter => break false,
+                    KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break true,
+                    KeyCode::Char(c) => token.push(c),
+                    KeyCode::Backspace => {
+                        token.pop();
+                    }
+                    _ => {}
+                }
+            }
+        };
+        drop(raw_mode);
+        println!();
+
+        if cancelled {
+            return Err("Cancelled".into());
+        }
+        Ok(token)
     }
+}
 
-    fn save_to_file(&self) -> Result<(), Box<dyn Error>> {
-        let yaml = serde_yaml::to_string(self)?;
-        let path = Self::get_path()?;
-        let path_str = path.to_string_lossy().to_string();
-        match fs::write(path, yaml) {
-            Ok(_) => {
-                println!("Config saved to file: {:?}", path_str);
-                Ok(())
+/// Keeps the terminal in raw mode until dropped, however the prompt ends.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn read_token(
+    token: &Option<String>,
+    file: &Option<PathBuf>,
+) -> Result<Option<String>, Box<dyn Error>> {
+    match (token, file) {
+        (Some(token), _) if !token.trim().is_empty() => Ok(Some(token.clone())),
+        (_, Some(file)) => {
+            let file = expand_home(file);
+            warn_if_exposed(&file);
+            let token = fs::read_to_string(&file)
+                .map_err(|e| format!("Could not read token file {}: {}", file.display(), e))?;
+            Ok(Some(token.trim().to_string()).filter(|token| !token.is_empty()))
+        }
+        (_, None) => Ok(None),
+    }
+}
+
+/// Writes a file only its owner can read, since it holds a session token.
+fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
+fn warn_if_exposed(path: &Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = fs::metadata(path) {
+            if metadata.permissions().mode() & 0o077 != 0 {
+                eprintln!(
+                    "Warning: {} can be read by other users, who could use your session token; run `chmod 600 {}`",
+                    path.display(),
+                    path.display()
+                );
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+fn lookup<'a>(value: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |node, key| node.get(*key))
+}
+
+fn mask(value: &mut Value) {
+    let Value::Mapping(mapping) = value else {
+        return;
+    };
+
+    for (key, value) in mapping.iter_mut() {
+        match value {
+            Value::String(token) if key.as_str() == Some("token") && token.len() > 8 => {
+                *token = format!("{}...", &token[..8]);
             }
-            Err(e) => Err(e.into()),
+            _ => mask(value),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conf(yaml: &str) -> Conf {
+        Conf::builder()
+            .preloaded(serde_yaml::from_str(yaml).unwrap())
+            .load()
+            .unwrap()
+    }
+
+    const PROFILES: &str = "
+token: main-token
+cache_dir: /tmp/aoc-cache
+http:
+  retries: 5
+  timeout_secs: 60
+profiles:
+  work:
+    token: work-token
+    http:
+      retries: 1
+";
+
+    #[test]
+    fn profiles_override_single_http_settings() {
+        let conf = conf(PROFILES);
+
+        let default = conf.account(None).unwrap();
+        assert_eq!(default.profile, DEFAULT_PROFILE);
+        assert_eq!(default.token.as_deref(), Some("main-token"));
+        assert_eq!((default.http.retries, default.http.timeout_secs), (5, 60));
+
+        let work = conf.account(Some("work")).unwrap();
+        assert_eq!(work.profile, "work");
+        assert_eq!(work.token.as_deref(), Some("work-token"));
+        assert_eq!((work.http.retries, work.http.timeout_secs), (1, 60));
+        assert_eq!(work.http.backoff_ms, 1000);
+        assert_eq!(work.cache_dir, default.cache_dir);
+    }
+
+    #[test]
+    fn bad_overrides_and_missing_profiles_are_named() {
+        let conf = conf(&PROFILES.replace("retries: 1", "retries: many"));
+
+        let error = conf.account(Some("work")).err().unwrap().to_string();
+        assert!(error.contains("profiles.work.http"), "{}", error);
+        assert!(conf.validate().is_err());
+
+        let error = conf.account(Some("home")).err().unwrap().to_string();
+        assert!(error.contains("\"home\""), "{}", error);
+    }
+}
@@ -0,0 +1,92 @@
+use std::{fs, path::PathBuf};
+
+use serde::Deserialize;
+use serde_yaml::Value;
+
+use crate::solution::DynSolution;
+
+/// An example loaded from `fixtures/<year>/<day>.yaml`, a list of entries
+/// with an `input` and the expected `part1` and/or `part2` answers.
+#[derive(Debug, Deserialize)]
+struct Fixture {
+    input: String,
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+fn answer(value: Option<Value>) -> Option<String> {
+    match value? {
+        Value::String(s) => Some(s),
+        Value::Number(n) => Some(n.to_string()),
+        other => panic!("unsupported answer in fixture: {:?}", other),
+    }
+}
+
+fn fixtures(year: i32, day: u32) -> Vec<(String, [Option<String>; 2])> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(year.to_string())
+        .join(format!("{:02}.yaml", day));
+
+    let Ok(yaml) = fs::read_to_string(&path) else {
+        return vec![];
+    };
+
+    serde_yaml::from_str::<Vec<Fixture>>(&yaml)
+        .unwrap_or_else(|e| panic!("invalid fixture {}: {}", path.display(), e))
+        .into_iter()
+        .map(|f| (f.input, [answer(f.part1), answer(f.part2)]))
+        .collect()
+}
+
+/// Runs every inline and fixture example for a day, checking each part that
+/// declares an expected answer. Fails if no part has one, since a test that
+/// checks nothing would pass however the day is changed.
+pub fn check(solution: &dyn DynSolution) {
+    let (year, day) = (solution.year(), solution.day());
+
+    let examples = solution
+        .examples()
+        .iter()
+        .map(|e| {
+            (
+                e.input.to_string(),
+                [e.part1.map(String::from), e.part2.map(String::from)],
+            )
+        })
+        .chain(fixtures(year, day));
+
+    let mut checked = 0;
+    for (i, (input, expected)) in examples.enumerate() {
+        for (p, expected) in expected.iter().enumerate() {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let part = p as u8 + 1;
+
+            let solved = solution
+                .solve(input.trim(), Some(part))
+                .unwrap_or_else(|e| panic!("{} day {} example {}: {}", year, day, i + 1, e));
+
+            assert_eq!(
+                solved.answers[p].as_deref(),
+                Some(expected.as_str()),
+                "{} day {} example {} part {}",
+                year,
+                day,
+                i + 1,
+                part
+            );
+            checked += 1;
+        }
+    }
+
+    assert!(
+        checked > 0,
+        "{} day {} has no expected answers; add EXAMPLES to the day or fill in fixtures/{}/{:02}.yaml",
+        year,
+        day,
+        year,
+        day
+    );
+}
@@ -0,0 +1,143 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{challenge::Verdict, conf::Conf};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: i32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted from this machine for one profile, stored as
+/// `<config dir>/history/<profile>.yaml`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(skip)]
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(profile: &str) -> Result<Self> {
+        let mut path = Conf::dir().map_err(|e| anyhow!("{}", e))?;
+        path.push("history");
+        fs::create_dir_all(&path)?;
+        path.push(format!("{}.yaml", profile));
+
+        let mut history = match fs::read_to_string(&path) {
+            Ok(yaml) => serde_yaml::from_str::<History>(&yaml)?,
+            Err(_) => History::default(),
+        };
+        history.path = path;
+
+        Ok(history)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, serde_yaml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn record(&mut self, year: i32, day: u32, part: u8, answer: &str, verdict: &Verdict) {
+        self.submissions.push(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: verdict.clone(),
+        });
+    }
+
+    fn submissions(&self, year: i32, day: u32, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    pub fn correct_answer(&self, year: i32, day: u32, part: u8) -> Option<&str> {
+        self.submissions(year, day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Refuses answers that cannot be right given what the site has already
+    /// told us: a known-correct answer, a known-wrong one, or one outside
+    /// the recorded too-high/too-low bounds.
+    pub fn check(&self, year: i32, day: u32, part: u8, answer: &str) -> Result<()> {
+        if let Some(correct) = self.correct_answer(year, day, part) {
+            bail!("Part {} is already solved with answer {}", part, correct);
+        }
+
+        let number = answer.parse::<i128>().ok();
+
+        for submission in self.submissions(year, day, part) {
+            let recorded = submission.answer.parse::<i128>().ok();
+
+            match (&submission.verdict, number, recorded) {
+                (Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow, _, _)
+                    if submission.answer == answer =>
+                {
+                    bail!("{} was already submitted and is wrong", answer);
+                }
+                (Verdict::TooHigh, Some(n), Some(high)) if n >= high => {
+                    bail!("{} is not below the known too-high answer {}", answer, high);
+                }
+                (Verdict::TooLow, Some(n), Some(low)) if n <= low => {
+                    bail!("{} is not above the known too-low answer {}", answer, low);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(submissions: &[(&str, Verdict)]) -> History {
+        let mut history = History::default();
+        for (answer, verdict) in submissions {
+            history.record(2024, 1, 1, answer, verdict);
+        }
+        history
+    }
+
+    #[test]
+    fn refuses_answers_at_or_past_a_recorded_bound() {
+        let history = history(&[("100", Verdict::TooHigh), ("10", Verdict::TooLow)]);
+
+        assert!(history.check(2024, 1, 1, "100").is_err());
+        assert!(history.check(2024, 1, 1, "10").is_err());
+        assert!(history.check(2024, 1, 1, "150").is_err());
+        assert!(history.check(2024, 1, 1, "5").is_err());
+        assert!(history.check(2024, 1, 1, "99").is_ok());
+        assert!(history.check(2024, 1, 1, "11").is_ok());
+        assert!(history.check(2024, 1, 2, "150").is_ok());
+    }
+
+    #[test]
+    fn compares_non_numeric_answers_only_for_equality() {
+        let history = history(&[("100", Verdict::TooHigh), ("ABC", Verdict::Wrong)]);
+
+        assert!(history.check(2024, 1, 1, "ABC").is_err());
+        assert!(history.check(2024, 1, 1, "XYZ").is_ok());
+    }
+
+    #[test]
+    fn refuses_anything_once_a_part_is_accepted() {
+        let history = history(&[("42", Verdict::Correct)]);
+
+        assert!(history.check(2024, 1, 1, "42").is_err());
+        assert!(history.check(2024, 1, 1, "43").is_err());
+        assert!(history.check(2024, 1, 2, "42").is_ok());
+    }
+}
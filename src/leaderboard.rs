@@ -0,0 +1,226 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Deserialize;
+
+use crate::unlock;
+
+/// A private leaderboard as served by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Stars earned, keyed by day and then by part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn star(&self, day: u32, part: u8) -> Option<DateTime<Utc>> {
+        let ts = self.completion_day_level.get(&day)?.get(&part)?.get_star_ts;
+        Utc.timestamp_opt(ts, 0).single()
+    }
+}
+
+fn format_duration(seconds: i64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| anyhow!("Could not read leaderboard: {}", e))
+    }
+
+    fn year(&self) -> Result<i32> {
+        self.event
+            .parse()
+            .map_err(|_| anyhow!("Unexpected leaderboard event {:?}", self.event))
+    }
+
+    fn days(&self) -> std::ops::RangeInclusive<u32> {
+        1..=self.year().map_or(25, unlock::days_in)
+    }
+
+    /// Members ordered by local score, then by who reached it first.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by_key(|m| {
+            let last = self
+                .days()
+                .flat_map(|day| [m.star(day, 1), m.star(day, 2)])
+                .flatten()
+                .max();
+            (std::cmp::Reverse(m.local_score), last, m.id)
+        });
+        members
+    }
+
+    /// The overall standings with a star per day: `*` for both parts and
+    /// `+` for part one only.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let tens: String = self
+            .days()
+            .map(|d| {
+                if d < 10 {
+                    ' '
+                } else {
+                    char::from(b'0' + (d / 10) as u8)
+                }
+            })
+            .collect();
+        let ones: String = self
+            .days()
+            .map(|d| char::from(b'0' + (d % 10) as u8))
+            .collect();
+
+        let owner = self
+            .members
+            .values()
+            .find(|m| m.id == self.owner_id)
+            .map_or_else(|| format!("#{}", self.owner_id), Member::display_name);
+        writeln!(out, "{} private leaderboard of {}\n", self.event, owner).unwrap();
+
+        writeln!(out, "{:>4} {:>5} {:>5}  {}", "", "", "", tens).unwrap();
+        writeln!(out, "{:>4} {:>5} {:>5}  {}", "", "Score", "Stars", ones).unwrap();
+
+        for (rank, member) in self.standings().iter().enumerate() {
+            let stars: String = self
+                .days()
+                .map(|day| match (member.star(day, 1), member.star(day, 2)) {
+                    (_, Some(_)) => '*',
+                    (Some(_), None) => '+',
+                    _ => '.',
+                })
+                .collect();
+
+            writeln!(
+                out,
+                "{:>3}) {:>5} {:>5}  {}  {}",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                stars,
+                member.display_name()
+            )
+            .unwrap();
+        }
+
+        out
+    }
+
+    /// Each member's time to each part of `day`, counted from the unlock,
+    /// and how long part two took after part one.
+    pub fn render_day(&self, day: u32) -> Result<String> {
+        let unlocked = unlock::unlocks_at(self.year()?, day)
+            .ok_or_else(|| anyhow!("Day {} is not part of the event", day))?;
+
+        let mut rows: Vec<_> = self
+            .members
+            .values()
+            .filter_map(|m| {
+                let part1 = m.star(day, 1)?;
+                Some((m, part1, m.star(day, 2)))
+            })
+            .collect();
+        rows.sort_by_key(|(m, part1, part2)| (part2.is_none(), *part2, *part1, m.id));
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "{:>4} {:>10} {:>10} {:>10}  Day {}",
+            "", "Part 1", "Part 2", "Delta", day
+        )
+        .unwrap();
+
+        for (rank, (member, part1, part2)) in rows.iter().enumerate() {
+            let since = |t: &DateTime<Utc>| format_duration((*t - unlocked).num_seconds());
+            let delta = part2.map(|p2| format_duration((p2 - *part1).num_seconds()));
+
+            writeln!(
+                out,
+                "{:>3}) {:>10} {:>10} {:>10}  {}",
+                rank + 1,
+                since(part1),
+                part2.as_ref().map(since).unwrap_or_default(),
+                delta.unwrap_or_default(),
+                member.display_name()
+            )
+            .unwrap();
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Leaderboard {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/leaderboard/private.json"
+        );
+        Leaderboard::parse(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn standings_are_ordered_by_score() {
+        let names: Vec<_> = fixture()
+            .standings()
+            .iter()
+            .map(|m| m.display_name())
+            .collect();
+
+        assert_eq!(names, ["Alice", "(anonymous user #3)", "Bob"]);
+    }
+
+    #[test]
+    fn render_marks_partial_days() {
+        let rendered = fixture().render();
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert_eq!(lines[0], "2024 private leaderboard of Alice");
+        assert_eq!(
+            lines[4],
+            "  1)    10     4  **.......................  Alice"
+        );
+        assert_eq!(lines[6], "  3)     3     1  +........................  Bob");
+    }
+
+    #[test]
+    fn render_day_shows_times_since_unlock_and_deltas() {
+        let rendered = fixture().render_day(1).unwrap();
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert_eq!(lines[1], "  1)   00:05:00   00:12:30   00:07:30  Alice");
+        assert_eq!(lines[3], "  3)   01:00:00                        Bob");
+    }
+}
@@ -1,47 +1,250 @@
-use std::{collections::BTreeSet, fmt::Display};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    process::ExitCode,
+};
 
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
+use clap::Parser;
 
+mod bench;
 mod challenge;
+mod cli;
 mod conf;
+#[cfg(test)]
+mod examples;
+mod history;
+mod leaderboard;
 mod menu;
+mod runner;
+mod scaffold;
+mod solution;
 mod solutions;
-mod year2015;
-mod year2024;
+mod unlock;
 
-type Solution = fn(&challenge::Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)>;
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
 
-fn main() {
-    let conf = conf::Conf::load_or_create().unwrap();
-    let solutions = solutions::all();
+fn main() -> ExitCode {
+    let cli = cli::Cli::parse();
 
-    let choices = solutions
-        .iter()
-        .map(|(year, days)| (*year, BTreeSet::from_iter(days.keys().copied())))
-        .collect();
+    // Config commands must work even when the file holds a bad value.
+    if let Some(cli::Command::Config(command)) = &cli.command {
+        return match config(command) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
 
-    let (selected_year, selected_day) = menu::select(&choices).unwrap();
+    // Inputs from a file or stdin can be solved without a token.
+    let needs_token = !cli.input.source().is_single();
+    let mut conf = match conf::Conf::load_or_create(cli.profile.as_deref(), needs_token) {
+        Ok(conf) => conf,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
 
-    if selected_year == 0 || selected_day == 0 {
-        return;
+    loop {
+        let Err(e) = run(&cli, &conf) else {
+            return ExitCode::SUCCESS;
+        };
+        eprintln!("{}", e);
+
+        let token = e
+            .downcast_ref()
+            .is_some_and(challenge::FetchError::is_token);
+        if !token || !prompt_for_new_token() {
+            return ExitCode::FAILURE;
+        }
+        let profile = cli.profile.as_deref().unwrap_or(conf::DEFAULT_PROFILE);
+        if let Err(e) = conf.replace_token(profile) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
     }
+}
 
-    match solutions.get(&selected_year).unwrap().get(&selected_day) {
-        Some(solution) => {
-            println!("Solving Year {}, Day {}...", selected_year, selected_day);
+fn run(cli: &cli::Cli, conf: &conf::Conf) -> Result<()> {
+    let solutions = solutions::all();
+    let account = conf
+        .account(cli.profile.as_deref())
+        .map_err(|e| anyhow!("{}", e))?;
+    let fetcher = challenge::Fetcher::new(account)?.source(cli.input.source());
+    let year = |year: Option<i32>| {
+        year.or(conf.year).ok_or_else(|| {
+            anyhow!("No year given; pass --year or set one with `aoc config set year <YEAR>`")
+        })
+    };
 
-            let fetcher = challenge::Fetcher::new(conf.token);
-            match solution(&fetcher) {
-                Ok((part_one, part_two)) => {
-                    println!("Part 1\n{}", part_one);
-                    println!("Part 2\n{}", part_two);
+    match &cli.command {
+        Some(cli::Command::Run(args)) => {
+            let fetcher = fetcher.refresh(args.refresh);
+            let format = args.format.unwrap_or(conf.format);
+            let selected = match (args.year, args.all) {
+                (None, true) => None,
+                (y, _) => Some(year(y)?),
+            };
+            match (selected, args.day, args.part) {
+                (Some(year), Some(day), Some(part)) if args.submit => {
+                    let answers = runner::run_day(&fetcher, &solutions, year, day, args.part)?;
+                    let verdict = runner::submit(
+                        &fetcher,
+                        year,
+                        day,
+                        part,
+                        answers[part as usize - 1].as_deref().unwrap_or_default(),
+                    )?;
+                    ensure!(verdict.is_accepted(), "Answer was not accepted");
+                    Ok(())
+                }
+                (Some(year), Some(day), _) if format == runner::Format::Text => {
+                    runner::run_day(&fetcher, &solutions, year, day, args.part).map(|_| ())
                 }
-                Err(e) => eprintln!("Error solving challenge: {}", e),
+                (year, day, _) => {
+                    runner::run_all(&fetcher, &solutions, year, day, args.part, format)
+                }
+            }
+        }
+        Some(cli::Command::Submit(args)) => {
+            let verdict = runner::submit(
+                &fetcher,
+                year(args.year)?,
+                args.day,
+                args.part,
+                &args.answer,
+            )?;
+            ensure!(verdict.is_accepted(), "Answer was not accepted");
+            Ok(())
+        }
+        Some(cli::Command::Show(args)) => {
+            runner::show(&fetcher.refresh(args.refresh), year(args.year)?, args.day)
+        }
+        Some(cli::Command::Bench(args)) => bench::run(
+            &fetcher,
+            &solutions,
+            year(args.year)?,
+            args.day,
+            bench::Options {
+                runs: args.runs as usize,
+                save: args.save.as_deref(),
+                compare: args.compare.as_deref(),
+                threshold: args.threshold,
+            },
+        ),
+        Some(cli::Command::Leaderboard(args)) => {
+            let year = year(args.year)?;
+            if let Some(day) = args.day {
+                unlock::ensure_day(year, day)?;
+            }
+            let json = fetcher.fetch_leaderboard(year, args.id)?;
+            let leaderboard = leaderboard::Leaderboard::parse(&json)?;
+            match args.day {
+                Some(day) => print!("{}", leaderboard.render_day(day)?),
+                None => print!("{}", leaderboard.render()),
             }
+            Ok(())
         }
-        None => eprintln!(
-            "No solution found for Year {}, Day {}",
-            selected_year, selected_day
+        Some(cli::Command::New(args)) => scaffold::new_day(
+            &fetcher.source(challenge::Source::Network),
+            year(args.year)?,
+            args.day,
         ),
+        Some(cli::Command::Wait(args)) => runner::wait(
+            &fetcher.source(challenge::Source::Network),
+            year(args.year)?,
+            args.day,
+        ),
+        Some(cli::Command::Cache(command)) => cache(fetcher.cache(), command),
+        Some(cli::Command::Config(_)) => unreachable!("config commands are handled in main"),
+        None => interactive(fetcher, &solutions, conf),
     }
 }
+
+fn cache(cache: &challenge::Cache, command: &cli::CacheCommand) -> Result<()> {
+    match command {
+        cli::CacheCommand::List => {
+            println!("Cached inputs in {}", cache.root().display());
+            for (year, day) in cache.list_inputs()? {
+                println!("{} day {:02}", year, day);
+            }
+        }
+        cli::CacheCommand::Clear { year, day } => {
+            let cleared = cache.clear(*year, *day)?;
+            println!("Cleared {} cached day(s)", cleared);
+        }
+    }
+
+    Ok(())
+}
+
+fn config(command: &cli::ConfigCommand) -> Result<()> {
+    let path = conf::Conf::get_path().map_err(|e| anyhow!("{}", e))?;
+
+    match command {
+        cli::ConfigCommand::Show => {
+            let conf = conf::Conf::load(&path).map_err(|e| anyhow!("{}", e))?;
+            print!("{}", conf.show().map_err(|e| anyhow!("{}", e))?);
+        }
+        cli::ConfigCommand::Set { key, value } => {
+            conf::Conf::set_value(key, value).map_err(|e| anyhow!("{}", e))?
+        }
+        cli::ConfigCommand::Path => println!("{}", path.display()),
+    }
+
+    Ok(())
+}
+
+fn interactive(
+    fetcher: challenge::Fetcher,
+    solutions: &runner::Solutions,
+    conf: &conf::Conf,
+) -> Result<()> {
+    if !menu::is_interactive() {
+        // The prompt reads its answers from stdin, leaving nothing for a
+        // solution to read.
+        ensure!(
+            *fetcher.input_source() != challenge::Source::Stdin,
+            "The numbered prompt reads stdin, so it cannot also be the input; use `aoc run -i -`"
+        );
+        return match menu::prompt(solutions).map_err(|e| anyhow!("{}", e))? {
+            Some((year, day, menu::Action::Solve)) => {
+                runner::run_day(&fetcher, solutions, year, day, None).map(|_| ())
+            }
+            Some((year, day, menu::Action::Show)) => runner::show(&fetcher, year, day),
+            None => Ok(()),
+        };
+    }
+
+    let choices: BTreeMap<_, _> = solutions
+        .iter()
+        .map(|(year, days)| (*year, BTreeSet::from_iter(days.keys().copied())))
+        .collect();
+
+    let palette = menu::Palette::new(&conf.theme, conf.color()).map_err(|e| anyhow!("{}", e))?;
+
+    let state = conf::MenuState::load();
+    let last = state.last_year.zip(state.last_day);
+    let selection =
+        menu::select(&choices, &palette, fetcher, solutions, last).map_err(|e| anyhow!("{}", e))?;
+
+    let left = conf::MenuState {
+        last_year: Some(selection.year),
+        last_day: Some(selection.day),
+    };
+    if left != state {
+        if let Err(e) = left.save() {
+            eprintln!("Could not save the menu selection: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+fn prompt_for_new_token() -> bool {
+    println!("Enter a new session token and try again? [y/N]");
+    let mut choice = String::new();
+    std::io::stdin().read_line(&mut choice).is_ok() && choice.trim().eq_ignore_ascii_case("y")
+}
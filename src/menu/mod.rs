@@ -3,27 +3,154 @@ use std::{
     error::Error,
     fmt::Write,
     io::{stdout, Write as _},
+    panic::{self, PanicHookInfo},
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
 };
 
+use base64::{prelude::BASE64_STANDARD, Engine};
+use chrono::Utc;
 use crossterm::{
     cursor::{self, Hide, MoveTo},
-    event::{self, Event, KeyCode, KeyEvent},
-    terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType::All},
-    ExecutableCommand,
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
+    style::{Color, Print, Stylize},
+    terminal::{
+        self, disable_raw_mode, enable_raw_mode, Clear,
+        ClearType::{All, FromCursorDown, UntilNewLine},
+    },
+    ExecutableCommand, QueueableCommand,
 };
 
+use crate::{challenge::Fetcher, conf::Theme, runner::Solutions, unlock};
+
+mod pager;
+mod prompt;
+mod results;
+
+use pager::Pager;
+pub use prompt::{is_interactive, prompt, Action};
+use results::{Finished, Results};
+
+/// Colors for the menu, or brackets around the selection when color is off.
+pub struct Palette {
+    selected_fg: Color,
+    selected_bg: Color,
+    dim: Color,
+    two_stars: Color,
+    one_star: Color,
+    color: bool,
+}
+
+impl Palette {
+    pub fn new(theme: &Theme, color: bool) -> Result<Self, Box<dyn Error>> {
+        let parse = |key: &str, name: &str| {
+            Color::try_from(name).map_err(|_| format!("Unknown color {:?} for theme.{}", name, key))
+        };
+
+        Ok(Self {
+            selected_fg: parse("selected_fg", &theme.selected_fg)?,
+            selected_bg: parse("selected_bg", &theme.selected_bg)?,
+            dim: parse("dim", &theme.dim)?,
+            two_stars: parse("two_stars", &theme.two_stars)?,
+            one_star: parse("one_star", &theme.one_star)?,
+            color,
+        })
+    }
+
+    /// Highlights a label padded with a leading space, such as `" 01 "`.
+    fn selected(&self, s: &str) -> String {
+        if self.color {
+            s.with(self.selected_fg).on(self.selected_bg).to_string()
+        } else {
+            format!(">{}", &s[1..])
+        }
+    }
+
+    /// A day in the grid, marked `*` for two stars, `+` for one, `!` when it
+    /// has a solution but no stars yet, and `-` while it is still locked.
+    /// Starred days are colored too, and locked ones dimmed.
+    fn day(&self, day: usize, stars: u8, solved: bool, locked: bool, selected: bool) -> String {
+        let marker = match (stars, solved) {
+            _ if locked => '-',
+            (2, _) => '*',
+            (1, _) => '+',
+            (_, true) => '!',
+            (_, false) => ' ',
+        };
+        let label = format!(" {:02}{}", day, marker);
+
+        match (selected, stars, solved) {
+            (true, _, _) => self.selected(&label),
+            (_, _, _) if !self.color => label,
+            _ if locked => self.dim(&label),
+            (_, 2, _) => label.with(self.two_stars).to_string(),
+            (_, 1, _) => label.with(self.one_star).to_string(),
+            (_, _, true) => label,
+            (_, _, false) => self.dim(&label),
+        }
+    }
+
+    fn dim(&self, s: &str) -> String {
+        if self.color {
+            s.with(self.dim).to_string()
+        } else {
+            s.to_string()
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 enum SelectType {
     Year,
     Day,
 }
 
+/// Lines taken by the title box, the countdown and the key hints.
+const CHROME_LINES: usize = 7;
+
+/// How much of the year list and day grid fit on screen.
+#[derive(Clone, Copy)]
+struct Layout {
+    /// Rows in the year list and in each column of the day grid; ten unless
+    /// the terminal is too short.
+    rows: usize,
+    /// The first year shown in the year list.
+    scroll: usize,
+}
+
+impl Layout {
+    /// Sizes the menu for a terminal `height` lines tall, scrolling the year
+    /// list just enough to keep `selected`, the index of the selected year,
+    /// in view. Some terminals report a height of zero, taken as unknown.
+    fn fit(self, height: u16, selected: usize) -> Self {
+        let rows = match height {
+            0 => 10,
+            height => (height as usize).saturating_sub(CHROME_LINES).clamp(1, 10),
+        };
+        let scroll = self
+            .scroll
+            .min(selected)
+            .max((selected + 1).saturating_sub(rows));
+
+        Self { rows, scroll }
+    }
+}
+
 fn render(
     selected: (i32, i32),
     choices: &BTreeMap<i32, BTreeSet<i32>>,
+    stars: &BTreeMap<i32, BTreeMap<u32, u8>>,
     select_type: SelectType,
+    layout: Layout,
+    palette: &Palette,
+    results: &Results,
 ) -> String {
     let (selected_year, selected_day) = selected;
+    let now = Utc::now();
     let mut output = String::new();
 
     writeln!(&mut output, "┏━━━━━━━━━━━━━━━━━━━━┓\r").unwrap();
@@ -37,15 +164,31 @@ fn render(
     }
     writeln!(&mut output, "┗━━━━━━┳━━━━━━━━━━━━━┛\r").unwrap();
 
-    for i in 1..=choices.len().max(10) {
+    let days = unlock::days_in(selected_year) as usize;
+    let years: Vec<_> = choices.keys().collect();
+    let Layout { rows, scroll } = layout;
+
+    for i in 0..rows {
         let mut line = String::new();
 
-        match choices.keys().nth(i - 1) {
+        // Arrows at the ends of the year list show there are more to scroll to.
+        let more = match i {
+            0 if scroll > 0 => '▲',
+            _ if i + 1 == rows && scroll + rows < years.len() => '▼',
+            _ => ' ',
+        };
+        match years.get(scroll + i) {
             Some(year) => {
-                if *year == selected_year {
-                    write!(&mut line, " {}", &color_bg_white(&format!(" {} ", year))).unwrap();
+                if **year == selected_year {
+                    write!(
+                        &mut line,
+                        "{}{}",
+                        more,
+                        &palette.selected(&format!(" {} ", year))
+                    )
+                    .unwrap();
                 } else {
-                    write!(&mut line, "  {} ", year).unwrap();
+                    write!(&mut line, "{} {} ", more, year).unwrap();
                 }
             }
             None => {
@@ -55,72 +198,295 @@ fn render(
 
         line += "┃";
 
-        if i > 10 {
-            continue;
-        }
-
-        for x in [0, 10, 20].iter() {
-            if *x == 20 && i > 5 {
-                break;
-            }
-
-            if i + x == selected_day as usize {
-                match select_type {
-                    SelectType::Year => {
-                        write!(&mut line, " {:02} ", i + x).unwrap();
-                    }
-                    SelectType::Day => {
-                        write!(&mut line, "{}", &color_bg_white(&format!(" {:02} ", i + x)))
-                            .unwrap();
-                    }
-                }
-            } else if choices
+        for day in (i + 1..=days).step_by(rows) {
+            let solved = choices.get(&selected_year).unwrap().contains(&(day as i32));
+            let earned = stars
                 .get(&selected_year)
-                .unwrap()
-                .contains(&((i + x) as i32))
-            {
-                write!(&mut line, " {:02} ", i + x).unwrap();
-            } else {
-                write!(&mut line, "{}", color_gray(&format!(" {:02} ", i + x))).unwrap();
-            }
+                .and_then(|days| days.get(&(day as u32)))
+                .copied()
+                .unwrap_or(0);
+            let locked = !unlock::is_unlocked(selected_year, day as u32, now);
+            let selected = matches!(select_type, SelectType::Day) && day == selected_day as usize;
+
+            line += &palette.day(day, earned, solved, locked, selected);
         }
 
         writeln!(&mut output, "{}\r", line).unwrap();
     }
 
+    let next = (1..=unlock::days_in(selected_year))
+        .find_map(|day| Some((day, unlock::time_until(selected_year, day, now)?)));
+    if let Some((day, wait)) = next {
+        writeln!(
+            &mut output,
+            "\r\n{}\r",
+            palette.dim(&format!(
+                "Day {} unlocks in {}",
+                day,
+                unlock::countdown(wait)
+            ))
+        )
+        .unwrap();
+    }
+
+    if let SelectType::Day = select_type {
+        writeln!(
+            &mut output,
+            "\r\n{}\r",
+            palette.dim("enter: solve  p: puzzle  0-9: jump  g/G: first/last  esc: back  q: quit")
+        )
+        .unwrap();
+    }
+
+    results.render(&mut output, palette);
+
     output
 }
 
-pub fn select(choices: &BTreeMap<i32, BTreeSet<i32>>) -> Result<(i32, i32), Box<dyn Error>> {
+/// Where the menu was left.
+pub struct Selection {
+    pub year: i32,
+    pub day: i32,
+}
+
+/// How long typed digits keep adding up to one number.
+const TYPING_WINDOW: Duration = Duration::from_secs(1);
+
+/// Digits typed in quick succession, to jump to a day or year by number.
+struct Typed {
+    digits: String,
+    at: Instant,
+}
+
+impl Typed {
+    /// Adds a digit and returns the first candidate `matches` accepts,
+    /// starting over from this digit when the number so far matches nothing.
+    fn push(&mut self, digit: char, matches: impl Fn(&str) -> Option<i32>) -> Option<i32> {
+        if self.at.elapsed() > TYPING_WINDOW {
+            self.digits.clear();
+        }
+        self.at = Instant::now();
+        self.digits.push(digit);
+
+        matches(&self.digits).or_else(|| {
+            self.digits = digit.to_string();
+            matches(&self.digits)
+        })
+    }
+}
+
+/// The day to move to from `day` when stepping `step` columns through a
+/// grid `rows` tall: the solved day in the nearest column that has one,
+/// closest to the same row.
+fn column_step(days: &BTreeSet<i32>, day: i32, rows: i32, step: i32, last: i32) -> Option<i32> {
+    let mut target = day;
+    loop {
+        target += step * rows;
+        let column = (target - 1).div_euclid(rows);
+        let (first, end) = (column * rows + 1, (column + 1) * rows);
+        if end < 1 || first > last {
+            return None;
+        }
+
+        let nearest = days.range(first..=end).min_by_key(|d| (**d - target).abs());
+        if let Some(day) = nearest {
+            return Some(*day);
+        }
+    }
+}
+
+/// Runs the menu until the user quits, solving days in a results pane below
+/// it. Opens at `start` when it still has a solution.
+pub fn select(
+    choices: &BTreeMap<i32, BTreeSet<i32>>,
+    palette: &Palette,
+    fetcher: Fetcher,
+    solutions: &Solutions,
+    start: Option<(i32, i32)>,
+) -> Result<Selection, Box<dyn Error>> {
     let mut selected_year = *choices.keys().max().unwrap();
     let mut selected_day = *choices.get(&selected_year).unwrap().iter().max().unwrap();
+    let mut select_type = SelectType::Year;
+
+    if let Some((year, day)) = start {
+        if choices.get(&year).is_some_and(|days| days.contains(&day)) {
+            (selected_year, selected_day) = (year, day);
+            select_type = SelectType::Day;
+        }
+    }
 
-    enable_raw_mode()?;
+    let terminal = RawTerminal::enter()?;
     let mut stdout = stdout();
-    stdout.execute(Hide)?;
 
-    let mut select_type = SelectType::Year;
-    loop {
-        stdout.execute(Clear(All))?;
-        stdout.execute(MoveTo(0, 0))?;
+    let mut layout = Layout {
+        rows: 10,
+        scroll: 0,
+    };
+    let mut typed = Typed {
+        digits: String::new(),
+        at: Instant::now(),
+    };
+    let mut results = Results::default();
+    let mut pager: Option<Pager> = None;
+    let mut stars = BTreeMap::new();
+    let (sender, receiver) = mpsc::channel();
+    // Printing would garble the screen, so retries show in the results pane.
+    let retrying = sender.clone();
+    let fetcher = &fetcher.on_retry(move |wait| {
+        let _ = retrying.send(Finished::Retrying(wait));
+    });
 
-        if !choices.get(&selected_year).unwrap().contains(&selected_day) {
-            selected_day = *choices.get(&selected_year).unwrap().iter().max().unwrap();
-        }
+    thread::scope(|scope| -> Result<_, Box<dyn Error>> {
+        // Stars are a nicety, filled in as they arrive. Once one year fails
+        // the site is likely unreachable, so the rest are not tried.
+        let loading = {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                for year in choices.keys() {
+                    let Ok(earned) = fetcher.fetch_stars(*year) else {
+                        break;
+                    };
+                    let _ = sender.send(Finished::Stars(*year, earned));
+                }
+            })
+        };
+
+        loop {
+            while let Ok(finished) = receiver.try_recv() {
+                if let Some((year, earned)) = results.finish(finished) {
+                    stars.insert(year, earned);
+                }
+            }
+            if let Some((title, markdown)) = results.take_puzzle() {
+                pager = Some(Pager::new(title, markdown));
+            }
 
-        print!(
-            "{}",
-            render((selected_year, selected_day), choices, select_type)
-        );
-        stdout.flush()?;
+            let days = choices.get(&selected_year).unwrap();
+            if !days.contains(&selected_day) {
+                selected_day = *days.iter().max().unwrap();
+            }
 
-        if let Event::Key(KeyEvent { code, .. }) = event::read()? {
-            match code {
-                KeyCode::Char('q') => {
-                    disable_raw_mode()?;
-                    stdout.execute(cursor::Show)?;
-                    return Ok((0, 0));
+            let size = terminal::size()?;
+            let index = choices.range(..selected_year).count();
+            layout = layout.fit(size.1, index);
+
+            let output = match &mut pager {
+                Some(pager) => {
+                    let mut output = String::new();
+                    pager.render(&mut output, size, palette);
+                    output
                 }
+                None => render(
+                    (selected_year, selected_day),
+                    choices,
+                    &stars,
+                    select_type,
+                    layout,
+                    palette,
+                    &results,
+                ),
+            };
+            // Overwrite in place rather than clearing, so the spinner does
+            // not flicker.
+            stdout.queue(MoveTo(0, 0))?;
+            for line in output.lines() {
+                stdout.queue(Print(line.trim_end_matches('\r')))?;
+                stdout.queue(Clear(UntilNewLine))?;
+                stdout.queue(Print("\r\n"))?;
+            }
+            stdout.queue(Clear(FromCursorDown))?;
+            stdout.flush()?;
+
+            // Redraw every second so countdowns to locked days stay current,
+            // and more often while the spinner runs.
+            let tick = if results.is_busy() {
+                Duration::from_millis(100)
+            } else {
+                Duration::from_secs(1)
+            };
+            if !event::poll(tick)? {
+                continue;
+            }
+
+            let (code, modifiers) = match event::read()? {
+                Event::Key(KeyEvent {
+                    code, modifiers, ..
+                }) => (code, modifiers),
+                Event::Mouse(MouseEvent {
+                    kind: kind @ (MouseEventKind::ScrollUp | MouseEventKind::ScrollDown),
+                    ..
+                }) => {
+                    if let Some(pager) = &mut pager {
+                        pager.scroll(if kind == MouseEventKind::ScrollUp {
+                            -3
+                        } else {
+                            3
+                        });
+                    }
+                    continue;
+                }
+                Event::Mouse(_) if pager.is_some() => continue,
+                Event::Mouse(MouseEvent {
+                    kind: MouseEventKind::Down(MouseButton::Left),
+                    column,
+                    row,
+                    ..
+                }) => {
+                    // The grid starts below the three lines of the title box,
+                    // with the year list in the first seven columns and then
+                    // days four columns wide after the divider.
+                    let line = (row as usize).wrapping_sub(3);
+                    if line >= layout.rows {
+                        continue;
+                    }
+
+                    if column < 7 {
+                        if let Some(year) = choices.keys().nth(layout.scroll + line) {
+                            selected_year = *year;
+                            select_type = SelectType::Year;
+                        }
+                    } else if column > 7 {
+                        let day = (line + 1 + (column as usize - 8) / 4 * layout.rows) as i32;
+                        if !days.contains(&day) {
+                            continue;
+                        }
+
+                        // A second click on the selected day runs it.
+                        if matches!(select_type, SelectType::Day) && day == selected_day {
+                            results.solve(scope, &sender, fetcher, solutions, (selected_year, day));
+                        }
+                        selected_day = day;
+                        select_type = SelectType::Day;
+                    }
+                    continue;
+                }
+                // Lines wrapped at the old width would be left behind.
+                Event::Resize(..) => {
+                    stdout.execute(Clear(All))?;
+                    continue;
+                }
+                _ => continue,
+            };
+
+            if let Some(open) = &mut pager {
+                let page = Pager::page(size.1);
+                match code {
+                    KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break,
+                    KeyCode::Esc | KeyCode::Char('q' | 'p') => pager = None,
+                    KeyCode::Up | KeyCode::Char('k') => open.scroll(-1),
+                    KeyCode::Down | KeyCode::Char('j') | KeyCode::Enter => open.scroll(1),
+                    KeyCode::PageUp | KeyCode::Char('b') => open.scroll(-page),
+                    KeyCode::PageDown | KeyCode::Char(' ') => open.scroll(page),
+                    KeyCode::Home | KeyCode::Char('g') => open.home(),
+                    KeyCode::End | KeyCode::Char('G') => open.end(),
+                    _ => {}
+                }
+                continue;
+            }
+
+            match code {
+                KeyCode::Char('q') => break,
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break,
                 KeyCode::Esc => match select_type {
                     SelectType::Year => {
                         continue;
@@ -131,16 +497,74 @@ pub fn select(choices: &BTreeMap<i32, BTreeSet<i32>>) -> Result<(i32, i32), Box<
                 },
                 KeyCode::Enter => match select_type {
                     SelectType::Year => {
-                        if choices.contains_key(&selected_year) {
-                            select_type = SelectType::Day;
-                            selected_day =
-                                *choices.get(&selected_year).unwrap().iter().max().unwrap();
+                        select_type = SelectType::Day;
+                        selected_day = *days.iter().max().unwrap();
+                    }
+                    SelectType::Day => results.solve(
+                        scope,
+                        &sender,
+                        fetcher,
+                        solutions,
+                        (selected_year, selected_day),
+                    ),
+                },
+                KeyCode::Char('p') => {
+                    if let SelectType::Day = select_type {
+                        results.fetch_puzzle(
+                            scope,
+                            &sender,
+                            fetcher,
+                            solutions,
+                            (selected_year, selected_day),
+                        );
+                    }
+                }
+                KeyCode::Char('r') => {
+                    if let Some(shown) = results.shown() {
+                        results.solve(scope, &sender, fetcher, solutions, shown);
+                    }
+                }
+                KeyCode::Char(key @ ('c' | 'C')) => {
+                    let part = if key == 'c' { 1 } else { 2 };
+                    match results.answer(part) {
+                        Some(answer) => {
+                            copy(answer)?;
+                            results.notify(format!("Copied part {}", part));
                         }
+                        None => results.notify(format!("There is no answer for part {}", part)),
+                    }
+                }
+                KeyCode::Char(key @ ('s' | 'S')) => {
+                    let part = if key == 's' { 1 } else { 2 };
+                    results.submit(scope, &sender, fetcher, part);
+                }
+                KeyCode::Char(digit) if digit.is_ascii_digit() => match select_type {
+                    // Years match by their last digits, so `24` finds 2024.
+                    SelectType::Year => {
+                        let year = typed.push(digit, |typed| {
+                            choices
+                                .keys()
+                                .rev()
+                                .find(|year| year.to_string().ends_with(typed))
+                                .copied()
+                        });
+                        selected_year = year.unwrap_or(selected_year);
                     }
                     SelectType::Day => {
-                        break;
+                        let day = typed.push(digit, |typed| {
+                            typed.parse().ok().filter(|day| days.contains(day))
+                        });
+                        selected_day = day.unwrap_or(selected_day);
                     }
                 },
+                KeyCode::Home | KeyCode::Char('g') => match select_type {
+                    SelectType::Year => selected_year = *choices.keys().next().unwrap(),
+                    SelectType::Day => selected_day = *days.first().unwrap(),
+                },
+                KeyCode::End | KeyCode::Char('G') => match select_type {
+                    SelectType::Year => selected_year = *choices.keys().next_back().unwrap(),
+                    SelectType::Day => selected_day = *days.last().unwrap(),
+                },
                 KeyCode::Left | KeyCode::Char('h') => match select_type {
                     SelectType::Year => {
                         if let Some((year, _)) = choices.range(..selected_year).next_back() {
@@ -148,12 +572,10 @@ pub fn select(choices: &BTreeMap<i32, BTreeSet<i32>>) -> Result<(i32, i32), Box<
                         }
                     }
                     SelectType::Day => {
-                        if choices.contains_key(&(selected_day - 10)) {
-                            selected_day -= 10;
-                        } else {
-                            selected_day =
-                                *choices.get(&selected_year).unwrap().iter().min().unwrap();
-                        }
+                        let last = unlock::days_in(selected_year) as i32;
+                        selected_day =
+                            column_step(days, selected_day, layout.rows as i32, -1, last)
+                                .unwrap_or(selected_day);
                     }
                 },
                 KeyCode::Right | KeyCode::Char('l') => match select_type {
@@ -163,12 +585,9 @@ pub fn select(choices: &BTreeMap<i32, BTreeSet<i32>>) -> Result<(i32, i32), Box<
                         }
                     }
                     SelectType::Day => {
-                        if choices.contains_key(&(selected_day + 10)) {
-                            selected_day += 10;
-                        } else {
-                            selected_day =
-                                *choices.get(&selected_year).unwrap().iter().max().unwrap();
-                        }
+                        let last = unlock::days_in(selected_year) as i32;
+                        selected_day = column_step(days, selected_day, layout.rows as i32, 1, last)
+                            .unwrap_or(selected_day);
                     }
                 },
                 KeyCode::Up | KeyCode::Char('k') => match select_type {
@@ -178,12 +597,8 @@ pub fn select(choices: &BTreeMap<i32, BTreeSet<i32>>) -> Result<(i32, i32), Box<
                         }
                     }
                     SelectType::Day => {
-                        if choices
-                            .get(&selected_year)
-                            .unwrap()
-                            .contains(&(selected_day - 1))
-                        {
-                            selected_day -= 1;
+                        if let Some(day) = days.range(..selected_day).next_back() {
+                            selected_day = *day;
                         }
                     }
                 },
@@ -194,30 +609,141 @@ pub fn select(choices: &BTreeMap<i32, BTreeSet<i32>>) -> Result<(i32, i32), Box<
                         }
                     }
                     SelectType::Day => {
-                        if choices
-                            .get(&selected_year)
-                            .unwrap()
-                            .contains(&(selected_day + 1))
-                        {
-                            selected_day += 1;
+                        if let Some(day) = days.range(selected_day + 1..).next() {
+                            selected_day = *day;
                         }
                     }
                 },
                 _ => {}
             }
         }
+
+        drop(terminal);
+        if results.is_busy() || !loading.is_finished() {
+            println!("Waiting for requests still running to finish...");
+        }
+
+        Ok(Selection {
+            year: selected_year,
+            day: selected_day,
+        })
+    })
+}
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo) + Send + Sync>;
+
+/// Keeps the terminal in raw mode with mouse capture until dropped. A panic
+/// on the menu's thread restores the terminal before its message is printed,
+/// so it can be read. Panics in solutions are caught on their own threads and
+/// shown in the results pane, so their messages would only garble the screen.
+struct RawTerminal {
+    previous_hook: Arc<PanicHook>,
+}
+
+impl RawTerminal {
+    fn enter() -> Result<Self, Box<dyn Error>> {
+        let previous_hook = Arc::new(panic::take_hook());
+        let hook = Arc::clone(&previous_hook);
+        let menu_thread = thread::current().id();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().id() == menu_thread {
+                restore();
+                hook(info);
+            }
+        }));
+
+        let terminal = RawTerminal { previous_hook };
+        enable_raw_mode()?;
+        stdout()
+            .execute(Hide)?
+            .execute(EnableMouseCapture)?
+            .execute(Clear(All))?;
+
+        Ok(terminal)
     }
+}
 
-    disable_raw_mode()?;
-    stdout.execute(cursor::Show)?;
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        restore();
+        // The hook cannot be changed while unwinding, and has already run.
+        if !thread::panicking() {
+            let previous_hook = Arc::clone(&self.previous_hook);
+            panic::set_hook(Box::new(move |info| previous_hook(info)));
+        }
+    }
+}
 
-    Ok((selected_year, selected_day))
+fn restore() {
+    let _ = disable_raw_mode();
+    let _ = stdout().execute(DisableMouseCapture);
+    let _ = stdout().execute(cursor::Show);
 }
 
-fn color_gray(s: &str) -> String {
-    format!("\x1b[90m{}\x1b[0m", s)
+/// Puts text on the clipboard with an OSC 52 escape sequence, which the
+/// terminal handles, so it also works over SSH.
+fn copy(text: &str) -> Result<(), Box<dyn Error>> {
+    let mut stdout = stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", BASE64_STANDARD.encode(text))?;
+    stdout.flush()?;
+
+    Ok(())
 }
 
-fn color_bg_white(s: &str) -> String {
-    format!("\x1b[30;47m{}\x1b[0m", s)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_steps_keep_the_row_or_find_the_nearest_solved_day() {
+        let days = BTreeSet::from([1, 2, 3, 12, 14, 25]);
+
+        assert_eq!(column_step(&days, 3, 10, 1, 25), Some(12));
+        assert_eq!(column_step(&days, 14, 10, -1, 25), Some(3));
+        assert_eq!(column_step(&days, 2, 10, 1, 25), Some(12));
+        assert_eq!(column_step(&days, 14, 10, 1, 25), Some(25));
+        assert_eq!(column_step(&days, 25, 10, 1, 25), None);
+        assert_eq!(column_step(&days, 2, 10, -1, 25), None);
+        assert_eq!(column_step(&days, 2, 5, 1, 25), Some(12));
+    }
+
+    #[test]
+    fn layout_shrinks_rows_and_scrolls_to_the_selected_year() {
+        let layout = Layout {
+            rows: 10,
+            scroll: 0,
+        };
+
+        assert_eq!(layout.fit(0, 3).rows, 10);
+        assert_eq!(layout.fit(40, 3).rows, 10);
+        assert_eq!(layout.fit(4, 3).rows, 1);
+
+        let short = layout.fit(CHROME_LINES as u16 + 4, 6);
+        assert_eq!((short.rows, short.scroll), (4, 3));
+        // Scrolled only as far as needed to bring the year back in view.
+        assert_eq!(short.fit(CHROME_LINES as u16 + 4, 5).scroll, 3);
+        assert_eq!(short.fit(CHROME_LINES as u16 + 4, 1).scroll, 1);
+    }
+
+    #[test]
+    fn typed_digits_add_up_or_start_over() {
+        let days = BTreeSet::from([1, 2, 12, 21]);
+        let matches = |typed: &str| typed.parse().ok().filter(|day| days.contains(day));
+        let mut typed = Typed {
+            digits: String::new(),
+            at: Instant::now(),
+        };
+
+        assert_eq!(typed.push('1', matches), Some(1));
+        assert_eq!(typed.push('2', matches), Some(12));
+        // 122 is no day, so the 2 starts a new number.
+        assert_eq!(typed.push('2', matches), Some(2));
+        assert_eq!(typed.push('1', matches), Some(21));
+        assert_eq!(typed.push('9', matches), None);
+
+        // After a pause, digits no longer add to the last ones.
+        typed.digits = "1".to_string();
+        typed.at = Instant::now() - TYPING_WINDOW * 2;
+        assert_eq!(typed.push('2', matches), Some(2));
+    }
 }
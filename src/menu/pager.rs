@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use super::Palette;
+
+/// A puzzle description shown in place of the menu, scrolled a line or a
+/// page at a time.
+pub struct Pager {
+    title: String,
+    text: String,
+    /// The first line shown.
+    top: usize,
+}
+
+impl Pager {
+    pub fn new(title: String, text: String) -> Self {
+        Self {
+            title,
+            text,
+            top: 0,
+        }
+    }
+
+    pub fn scroll(&mut self, by: isize) {
+        self.top = self.top.saturating_add_signed(by);
+    }
+
+    pub fn home(&mut self) {
+        self.top = 0;
+    }
+
+    pub fn end(&mut self) {
+        self.top = usize::MAX;
+    }
+
+    /// Draws as much as fits in a terminal `width` columns and `height` lines
+    /// tall, below a title and above the key hints.
+    pub fn render(&mut self, output: &mut String, (width, height): (u16, u16), palette: &Palette) {
+        // Some terminals report a size of zero, taken as unknown.
+        let width = match width {
+            0 => 80,
+            width => width as usize,
+        };
+        let rows = match height {
+            0 => 24,
+            height => height as usize,
+        }
+        .saturating_sub(4)
+        .max(1);
+
+        let lines = wrap(&self.text, width);
+        self.top = self.top.min(lines.len().saturating_sub(rows));
+
+        writeln!(output, "{}\r\n\r", self.title).unwrap();
+        for line in lines.iter().skip(self.top).take(rows) {
+            writeln!(output, "{}\r", line).unwrap();
+        }
+
+        let shown = (self.top + rows).min(lines.len());
+        writeln!(
+            output,
+            "\r\n{}\r",
+            palette.dim(&format!(
+                "{}/{}  j/k: scroll  space/b: page  g/G: top/bottom  esc: back",
+                shown,
+                lines.len()
+            ))
+        )
+        .unwrap();
+    }
+
+    /// Lines shown per page, for paging by the same amount.
+    pub fn page(height: u16) -> isize {
+        match height {
+            0 => 20,
+            height => (height as isize - 4).max(1),
+        }
+    }
+}
+
+/// Breaks each line of `text` at spaces to fit `width` columns, cutting words
+/// longer than a whole line.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for line in text.lines() {
+        if line.chars().count() <= width {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        let mut current = String::new();
+        for word in line.split(' ') {
+            let fits = current.chars().count() + 1 + word.chars().count() <= width;
+            if !current.is_empty() && !fits {
+                lines.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+
+            while current.chars().count() > width {
+                let rest = current.split_off(current.char_indices().nth(width).unwrap().0);
+                lines.push(std::mem::replace(&mut current, rest));
+            }
+        }
+        lines.push(current);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_at_spaces_and_cuts_long_words() {
+        assert_eq!(
+            wrap("one two three\n\n  code\nabcdefghij", 7),
+            ["one two", "three", "", "  code", "abcdefg", "hij"]
+        );
+    }
+
+    #[test]
+    fn scrolling_stops_at_the_last_page() {
+        let theme = crate::conf::Theme {
+            selected_fg: "black".to_string(),
+            selected_bg: "grey".to_string(),
+            dim: "dark_grey".to_string(),
+            two_stars: "yellow".to_string(),
+            one_star: "blue".to_string(),
+        };
+        let palette = Palette::new(&theme, false).unwrap();
+        let text = (1..=10)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut pager = Pager::new("Title".to_string(), text);
+
+        pager.end();
+        let mut output = String::new();
+        pager.render(&mut output, (80, 8), &palette);
+
+        assert_eq!(pager.top, 6);
+        assert!(output.contains("10/10"));
+        assert!(!output.contains("\n6\r"));
+    }
+}
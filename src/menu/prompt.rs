@@ -0,0 +1,130 @@
+use std::{
+    error::Error,
+    io::{stdin, stdout, BufRead, IsTerminal, Write},
+};
+
+use crate::runner::Solutions;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Solve,
+    Show,
+}
+
+/// The year and day picked, and what to do with it.
+pub type Choice = (i32, i32, Action);
+
+/// The full-screen menu needs a terminal on both ends; anything else, such
+/// as a pipe or a CI log, gets the numbered prompt instead.
+pub fn is_interactive() -> bool {
+    stdin().is_terminal() && stdout().is_terminal()
+}
+
+/// Asks for a year, a day and what to do with it by number, one line each.
+/// An empty line picks the latest year or day. Returns `None` at the end of
+/// input.
+pub fn prompt(solutions: &Solutions) -> Result<Option<Choice>, Box<dyn Error>> {
+    prompt_from(solutions, &mut stdin().lock(), &mut stdout())
+}
+
+fn prompt_from(
+    solutions: &Solutions,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<Option<Choice>, Box<dyn Error>> {
+    let years: Vec<_> = solutions.keys().copied().collect();
+    let labels: Vec<_> = years.iter().map(i32::to_string).collect();
+    let Some(year) = choose(input, output, "year", &labels)?.map(|i| years[i]) else {
+        return Ok(None);
+    };
+
+    let days: Vec<_> = solutions[&year].keys().copied().collect();
+    let labels: Vec<_> = solutions[&year]
+        .iter()
+        .map(|(day, solution)| format!("Day {:02}: {}", day, solution.title()))
+        .collect();
+    let Some(day) = choose(input, output, "day", &labels)?.map(|i| days[i]) else {
+        return Ok(None);
+    };
+
+    write!(output, "Solve it, or show the puzzle? [S/p] ")?;
+    output.flush()?;
+    let action = match read_line(input)?.as_deref().map(str::trim) {
+        None => return Ok(None),
+        Some("p" | "P") => Action::Show,
+        Some("" | "s" | "S") => Action::Solve,
+        Some(other) => return Err(format!("Expected s or p, got {:?}", other).into()),
+    };
+
+    Ok(Some((year, day, action)))
+}
+
+/// Lists `labels` numbered from one and returns the index of the one chosen.
+fn choose(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    what: &str,
+    labels: &[String],
+) -> Result<Option<usize>, Box<dyn Error>> {
+    for (i, label) in labels.iter().enumerate() {
+        writeln!(output, "{:>3}) {}", i + 1, label)?;
+    }
+    write!(output, "Choose a {} [{}]: ", what, labels.len())?;
+    output.flush()?;
+
+    let Some(line) = read_line(input)? else {
+        return Ok(None);
+    };
+
+    match line.trim() {
+        "" => Ok(Some(labels.len() - 1)),
+        choice => match choice.parse::<usize>() {
+            Ok(n) if (1..=labels.len()).contains(&n) => Ok(Some(n - 1)),
+            _ => Err(format!("Choose a {} between 1 and {}", what, labels.len()).into()),
+        },
+    }
+}
+
+fn read_line(input: &mut impl BufRead) -> Result<Option<String>, Box<dyn Error>> {
+    let mut line = String::new();
+    match input.read_line(&mut line)? {
+        0 => Ok(None),
+        _ => Ok(Some(line)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input: &str) -> Result<Option<Choice>, Box<dyn Error>> {
+        prompt_from(
+            &crate::solutions::all(),
+            &mut input.as_bytes(),
+            &mut Vec::new(),
+        )
+    }
+
+    #[test]
+    fn picks_by_number_with_the_latest_as_default() {
+        let solutions = crate::solutions::all();
+        let latest = *solutions.keys().last().unwrap();
+        let last_day = *solutions[&latest].keys().last().unwrap();
+        let first_day = *solutions[&latest].keys().next().unwrap();
+
+        assert_eq!(
+            run("\n\n\n").unwrap(),
+            Some((latest, last_day, Action::Solve))
+        );
+        assert_eq!(
+            run(&format!("{}\n1\np\n", solutions.len())).unwrap(),
+            Some((latest, first_day, Action::Show))
+        );
+    }
+
+    #[test]
+    fn stops_at_end_of_input_and_rejects_other_numbers() {
+        assert_eq!(run("").unwrap(), None);
+        assert!(run("0\n").is_err());
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::mpsc::Sender,
+    thread::Scope,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+use crate::{
+    challenge::{Fetcher, Verdict},
+    history::History,
+    runner::{self, Solutions},
+    solution::Solved,
+};
+
+use super::Palette;
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Work done on a background thread, sent back to the menu when it ends.
+pub enum Finished {
+    /// The answers, and the time taken including the fetch.
+    Solved(Result<Solved>, Duration),
+    /// The verdict for a part, and the year's stars fetched again after a
+    /// right answer.
+    Submitted(u8, Result<Verdict>, Option<BTreeMap<u32, u8>>),
+    /// A year's stars, loaded when the menu opens.
+    Stars(i32, BTreeMap<u32, u8>),
+    /// A day's puzzle description, with the title to show above it.
+    Puzzle(String, Result<String>),
+    /// A request failed and is retried after the wait.
+    Retrying(Duration),
+}
+
+/// The pane under the menu showing the last day run. Solving and submitting
+/// happen on background threads so the menu can still be used meanwhile.
+#[derive(Default)]
+pub struct Results {
+    /// The year and day shown, with the solution's title.
+    day: Option<(i32, i32, &'static str)>,
+    busy: Option<(String, Instant)>,
+    /// The answers and the time taken including the fetch, or the error.
+    outcome: Option<Result<(Solved, Duration), String>>,
+    accepted: [Option<String>; 2],
+    message: Option<String>,
+    /// A fetched description waiting to be shown, with its title.
+    puzzle: Option<(String, String)>,
+}
+
+impl Results {
+    pub fn is_busy(&self) -> bool {
+        self.busy.is_some()
+    }
+
+    pub fn shown(&self) -> Option<(i32, i32)> {
+        self.day.map(|(year, day, _)| (year, day))
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match &self.outcome {
+            Some(Ok((solved, _))) => solved.answers[part as usize - 1].as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn notify(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+    }
+
+    /// The description fetched by [`Results::fetch_puzzle`], once.
+    pub fn take_puzzle(&mut self) -> Option<(String, String)> {
+        self.puzzle.take()
+    }
+
+    /// Runs a day in the background, replacing whatever was shown.
+    pub fn solve<'scope, 'env>(
+        &mut self,
+        scope: &'scope Scope<'scope, 'env>,
+        sender: &Sender<Finished>,
+        fetcher: &'env Fetcher,
+        solutions: &Solutions,
+        (year, day): (i32, i32),
+    ) {
+        if self.is_busy() {
+            return;
+        }
+
+        let solution = match runner::find(solutions, year, day) {
+            Ok(solution) => solution,
+            Err(e) => return self.notify(e.to_string()),
+        };
+
+        let history = fetcher
+            .input_source()
+            .is_network()
+            .then(|| History::load(fetcher.account()).ok())
+            .flatten();
+        self.accepted = [1, 2].map(|part| {
+            history
+                .as_ref()?
+                .correct_answer(year, day as u32, part)
+                .map(str::to_string)
+        });
+
+        self.day = Some((year, day, solution.title()));
+        self.outcome = None;
+        self.message = None;
+        self.busy = Some(("Solving".to_string(), Instant::now()));
+
+        let sender = sender.clone();
+        scope.spawn(move || {
+            let started = Instant::now();
+            let solved = fetcher
+                .fetch_challenge(year, day as u32)
+                .and_then(|input| runner::solve_caught(solution, &input, None));
+            let _ = sender.send(Finished::Solved(solved, started.elapsed()));
+        });
+    }
+
+    /// Fetches a day's puzzle description in the background.
+    pub fn fetch_puzzle<'scope, 'env>(
+        &mut self,
+        scope: &'scope Scope<'scope, 'env>,
+        sender: &Sender<Finished>,
+        fetcher: &'env Fetcher,
+        solutions: &Solutions,
+        (year, day): (i32, i32),
+    ) {
+        if self.is_busy() {
+            return;
+        }
+
+        let title = match runner::find(solutions, year, day) {
+            Ok(solution) => format!("Year {}, Day {}: {}", year, day, solution.title()),
+            Err(_) => format!("Year {}, Day {}", year, day),
+        };
+        self.message = None;
+        self.busy = Some(("Fetching the puzzle".to_string(), Instant::now()));
+
+        let sender = sender.clone();
+        scope.spawn(move || {
+            let markdown = fetcher.fetch_puzzle(year, day as u32);
+            let _ = sender.send(Finished::Puzzle(title, markdown));
+        });
+    }
+
+    /// Submits a part of the shown answers in the background.
+    pub fn submit<'scope, 'env>(
+        &mut self,
+        scope: &'scope Scope<'scope, 'env>,
+        sender: &Sender<Finished>,
+        fetcher: &'env Fetcher,
+        part: u8,
+    ) {
+        if self.is_busy() {
+            return;
+        }
+        let (Some((year, day)), Some(answer)) = (self.shown(), self.answer(part)) else {
+            return self.notify(format!("There is no answer for part {} to submit", part));
+        };
+
+        let answer = answer.to_string();
+        self.message = None;
+        self.busy = Some((format!("Submitting part {}", part), Instant::now()));
+
+        let sender = sender.clone();
+        scope.spawn(move || {
+            let verdict = runner::send_answer(fetcher, year, day, part, &answer);
+            let stars = match verdict {
+                Ok(Verdict::Correct) => fetcher.fetch_stars(year).ok(),
+                _ => None,
+            };
+            let _ = sender.send(Finished::Submitted(part, verdict, stars));
+        });
+    }
+
+    /// Takes in finished work, returning the shown year's stars when they
+    /// changed.
+    pub fn finish(&mut self, finished: Finished) -> Option<(i32, BTreeMap<u32, u8>)> {
+        match finished {
+            Finished::Stars(year, stars) => Some((year, stars)),
+            Finished::Retrying(wait) => {
+                self.notify(format!("Request failed, retrying in {:.1?}...", wait));
+                None
+            }
+            Finished::Puzzle(title, markdown) => {
+                self.busy = None;
+                match markdown {
+                    Ok(markdown) => self.puzzle = Some((title, markdown)),
+                    Err(e) => self.notify(format!("{:#}", e)),
+                }
+                None
+            }
+            Finished::Solved(solved, elapsed) => {
+                self.busy = None;
+                self.outcome = Some(
+                    solved
+                        .map(|solved| (solved, elapsed))
+                        .map_err(|e| format!("{:#}", e)),
+                );
+                None
+            }
+            Finished::Submitted(part, verdict, stars) => {
+                self.busy = None;
+                match verdict {
+                    Ok(verdict) => {
+                        if verdict == Verdict::Correct {
+                            self.accepted[part as usize - 1] =
+                                self.answer(part).map(str::to_string);
+                        }
+                        self.notify(verdict.to_string());
+                    }
+                    Err(e) => self.notify(format!("{:#}", e)),
+                }
+                Some((self.shown()?.0, stars?))
+            }
+        }
+    }
+
+    pub fn render(&self, output: &mut String, palette: &Palette) {
+        // Fetching a puzzle can show progress before any day was run.
+        match self.day {
+            Some((year, day, title)) => {
+                writeln!(output, "\r\nYear {}, Day {}: {}\r", year, day, title).unwrap()
+            }
+            None if self.busy.is_some() || self.message.is_some() => {
+                writeln!(output, "\r").unwrap()
+            }
+            None => return,
+        }
+
+        if let Some((label, started)) = &self.busy {
+            let elapsed = started.elapsed();
+            let frame = SPINNER[(elapsed.as_millis() / 100) as usize % SPINNER.len()];
+            writeln!(output, "{} {}... {:.1?}\r", frame, label, elapsed).unwrap();
+        }
+
+        match &self.outcome {
+            Some(Ok((solved, elapsed))) => {
+                writeln!(
+                    output,
+                    "Parsed in {:.2?}, done in {:.2?}\r",
+                    solved.parse, elapsed
+                )
+                .unwrap();
+
+                for (i, answer) in solved.answers.iter().enumerate() {
+                    let (Some(answer), Some(time)) = (answer, solved.parts[i]) else {
+                        continue;
+                    };
+                    let note = match &self.accepted[i] {
+                        Some(correct) if correct == answer => palette.dim(" (accepted)"),
+                        Some(correct) => palette.dim(&format!(" (was accepted as {})", correct)),
+                        None => String::new(),
+                    };
+
+                    writeln!(
+                        output,
+                        "Part {} ({:.2?}): {}{}\r",
+                        i + 1,
+                        time,
+                        answer,
+                        note
+                    )
+                    .unwrap();
+                }
+            }
+            Some(Err(e)) => writeln!(output, "Error: {}\r", e).unwrap(),
+            None => {}
+        }
+
+        if let Some(message) = &self.message {
+            writeln!(output, "{}\r", message).unwrap();
+        }
+
+        if self.day.is_some() {
+            writeln!(
+                output,
+                "\r\n{}\r",
+                palette.dim("r: re-run  c/C: copy part 1/2  s/S: submit part 1/2")
+            )
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use rayon::prelude::*;
+
+use crate::{
+    challenge::{FetchError, Fetcher},
+    history::History,
+    solution::{DynSolution, Solved},
+};
+
+use super::Solutions;
+
+/// How an answer compares with the one adventofcode.com accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// Differs from the accepted answer, which is kept here.
+    Fail(String),
+    /// No accepted answer is recorded, or the input is not the account's own.
+    Unchecked,
+    Error(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail(_) | Status::Error(_))
+    }
+}
+
+/// One row of the summary: a single part of a single day.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub year: i32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed: Option<Duration>,
+    pub status: Status,
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    part.map_or(vec![1, 2], |p| vec![p])
+}
+
+fn failed(solution: &dyn DynSolution, part: Option<u8>, error: String) -> Vec<Outcome> {
+    parts(part)
+        .into_iter()
+        .map(|p| Outcome {
+            year: solution.year(),
+            day: solution.day(),
+            part: p,
+            answer: None,
+            elapsed: None,
+            status: Status::Error(error.clone()),
+        })
+        .collect()
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Solves a day, turning a panic in the solution into an error.
+pub fn solve_caught(solution: &dyn DynSolution, input: &str, part: Option<u8>) -> Result<Solved> {
+    panic::catch_unwind(AssertUnwindSafe(|| solution.solve(input, part)))
+        .unwrap_or_else(|payload| Err(anyhow!("panicked: {}", panic_message(payload))))
+}
+
+fn solve(
+    solution: &dyn DynSolution,
+    input: &str,
+    part: Option<u8>,
+    history: Option<&History>,
+) -> Vec<Outcome> {
+    let solved = match solve_caught(solution, input, part) {
+        Ok(solved) => solved,
+        Err(e) => return failed(solution, part, e.to_string()),
+    };
+
+    parts(part)
+        .into_iter()
+        .map(|p| {
+            let i = p as usize - 1;
+            let answer = solved.answers[i].clone();
+            let correct =
+                history.and_then(|h| h.correct_answer(solution.year(), solution.day(), p));
+            let status = match (answer.as_deref(), correct) {
+                (Some(answer), Some(correct)) if answer == correct => Status::Pass,
+                (_, Some(correct)) => Status::Fail(correct.to_string()),
+                (_, None) => Status::Unchecked,
+            };
+
+            Outcome {
+                year: solution.year(),
+                day: solution.day(),
+                part: p,
+                answer,
+                elapsed: solved.parts[i],
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Solves the selected day, every registered day of `year`, or every day of
+/// every year, spreading the days across CPU cores. Inputs are fetched one
+/// at a time beforehand so the site only ever sees a single request in
+/// flight.
+pub fn run_batch(
+    fetcher: &Fetcher,
+    solutions: &Solutions,
+    year: Option<i32>,
+    day: Option<i32>,
+    part: Option<u8>,
+) -> Result<Vec<Outcome>> {
+    let days: Vec<&dyn DynSolution> = match (year, day) {
+        (Some(year), Some(day)) => vec![super::find(solutions, year, day)?],
+        (Some(year), None) => solutions
+            .get(&year)
+            .ok_or_else(|| anyhow!("No solutions found for Year {}", year))?
+            .values()
+            .copied()
+            .collect(),
+        (None, _) => solutions
+            .values()
+            .flat_map(|days| days.values().copied())
+            .collect(),
+    };
+
+    ensure!(
+        days.len() == 1 || !fetcher.input_source().is_single(),
+        "{} can only be used for a single day, but {} are selected",
+        fetcher.input_source(),
+        days.len()
+    );
+
+    let history = History::load(fetcher.account())?;
+    let history = fetcher.input_source().is_network().then_some(&history);
+
+    // A rejected token fails every day the same way, so stop at the first.
+    let mut inputs = Vec::with_capacity(days.len());
+    for solution in &days {
+        match fetcher.fetch_challenge(solution.year(), solution.day()) {
+            Err(e) if e.downcast_ref().is_some_and(FetchError::is_token) => return Err(e),
+            input => inputs.push(input),
+        }
+    }
+
+    let outcomes = days
+        .par_iter()
+        .zip(inputs)
+        .flat_map_iter(|(solution, input)| match input {
+            Ok(input) => solve(*solution, &input, part, history),
+            Err(e) => failed(*solution, part, e.to_string()),
+        })
+        .collect();
+
+    Ok(outcomes)
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    let width = outcomes
+        .iter()
+        .filter_map(|o| o.answer.as_ref().map(String::len))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:>4} {:>3} {:>4}  {:<width$}  {:>10}  Result",
+        "Year", "Day", "Part", "Answer", "Elapsed"
+    );
+
+    for o in outcomes {
+        let elapsed = o.elapsed.map(|e| format!("{:.2?}", e)).unwrap_or_default();
+        let result = match &o.status {
+            Status::Pass => "pass".to_string(),
+            Status::Fail(correct) => format!("FAIL (expected {})", correct),
+            Status::Unchecked => "-".to_string(),
+            Status::Error(e) => format!("ERROR: {}", e),
+        };
+
+        println!(
+            "{:>4} {:>3} {:>4}  {:<width$}  {:>10}  {}",
+            o.year,
+            o.day,
+            o.part,
+            o.answer.as_deref().unwrap_or_default(),
+            elapsed,
+            result
+        );
+    }
+}
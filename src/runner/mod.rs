@@ -0,0 +1,202 @@
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, ensure, Result};
+use chrono::Utc;
+
+use crate::{
+    challenge::{FetchError, Fetcher, Verdict},
+    history::History,
+    solution::{Answers, DynSolution},
+    unlock,
+};
+
+mod batch;
+mod output;
+
+pub use batch::solve_caught;
+use batch::{print_table, run_batch, Outcome, Status};
+use output::Record;
+
+pub use output::Format;
+
+pub type Solutions = BTreeMap<i32, BTreeMap<i32, &'static dyn DynSolution>>;
+
+pub fn find(solutions: &Solutions, year: i32, day: i32) -> Result<&'static dyn DynSolution> {
+    solutions
+        .get(&year)
+        .and_then(|days| days.get(&day))
+        .copied()
+        .ok_or_else(|| anyhow!("No solution found for Year {}, Day {}", year, day))
+}
+
+pub fn run_day(
+    fetcher: &Fetcher,
+    solutions: &Solutions,
+    year: i32,
+    day: i32,
+    part: Option<u8>,
+) -> Result<Answers> {
+    let solution = find(solutions, year, day)?;
+
+    println!(
+        "Solving Year {}, Day {}: {} ({})...",
+        year,
+        day,
+        solution.title(),
+        fetcher.input_source()
+    );
+
+    let input = fetcher.fetch_challenge(solution.year(), solution.day())?;
+    let solved = solution
+        .solve(&input, part)
+        .map_err(|e| anyhow!("Error solving Year {}, Day {}: {}", year, day, e))?;
+
+    let history = History::load(fetcher.account())?;
+    println!("Parsed in {:.2?}", solved.parse);
+
+    for (i, answer) in solved.answers.iter().enumerate() {
+        let p = i as u8 + 1;
+        let (Some(answer), Some(elapsed)) = (answer, solved.parts[i]) else {
+            continue;
+        };
+
+        println!("Part {} ({:.2?})\n{}", p, elapsed, answer);
+        match history.correct_answer(year, day as u32, p) {
+            Some(correct) if correct != answer && fetcher.input_source().is_network() => {
+                eprintln!("Regression: Part {} was accepted as {}", p, correct)
+            }
+            _ => {}
+        }
+    }
+
+    Ok(solved.answers)
+}
+
+/// Runs the selected day, every day of `year`, or every registered day, and
+/// reports the results in `format`. Fails if any part errored or no longer
+/// matches its accepted answer.
+pub fn run_all(
+    fetcher: &Fetcher,
+    solutions: &Solutions,
+    year: Option<i32>,
+    day: Option<i32>,
+    part: Option<u8>,
+    format: Format,
+) -> Result<()> {
+    let start = Instant::now();
+    let outcomes = run_batch(fetcher, solutions, year, day, part)?;
+    let failed = outcomes.iter().filter(|o| o.status.is_failure()).count();
+    let total = outcomes.len();
+
+    if format == Format::Text {
+        print_table(&outcomes);
+        println!("Total: {:.2?}", start.elapsed());
+    } else {
+        let input = fetcher.input_source().to_string();
+        let records: Vec<_> = outcomes
+            .into_iter()
+            .map(|o| Record::new(o, &input))
+            .collect();
+        output::write(format, &records)?;
+    }
+
+    match failed {
+        0 => Ok(()),
+        n => Err(anyhow!("{} of {} parts failed", n, total)),
+    }
+}
+
+pub fn submit(fetcher: &Fetcher, year: i32, day: i32, part: u8, answer: &str) -> Result<Verdict> {
+    println!(
+        "Submitting {} for Year {}, Day {}, Part {}...",
+        answer, year, day, part
+    );
+
+    let verdict = send_answer(fetcher, year, day, part, answer)?;
+    println!("{}", verdict);
+
+    Ok(verdict)
+}
+
+/// Submits an answer without printing anything, refusing ones the history
+/// already rules out and recording the verdict.
+pub fn send_answer(
+    fetcher: &Fetcher,
+    year: i32,
+    day: i32,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict> {
+    unlock::ensure_day(year, day as u32)?;
+    ensure!(
+        fetcher.input_source().is_network(),
+        "Only answers for your own puzzle input can be submitted, not {}",
+        fetcher.input_source()
+    );
+
+    let mut history = History::load(fetcher.account())?;
+    history.check(year, day as u32, part, answer)?;
+
+    let verdict = fetcher.submit_answer(year, day as u32, part, answer)?;
+
+    if !matches!(verdict, Verdict::RateLimited(_) | Verdict::Unknown(_)) {
+        history.record(year, day as u32, part, answer, &verdict);
+        history.save()?;
+    }
+
+    Ok(verdict)
+}
+
+/// Sleeps until a puzzle unlocks, showing the time left, then fetches its
+/// input and description straight away. The site can lag the clock by a
+/// moment, so a puzzle that still reports itself locked is retried briefly.
+pub fn wait(fetcher: &Fetcher, year: i32, day: u32) -> Result<()> {
+    ensure!(
+        unlock::unlocks_at(year, day).is_some(),
+        "Day {} is not part of the {} event",
+        day,
+        year
+    );
+
+    while let Some(left) = unlock::time_until(year, day, Utc::now()) {
+        print!("\rDay {} unlocks in {} ", day, unlock::countdown(left));
+        std::io::stdout().flush()?;
+        thread::sleep(left.min(Duration::from_secs(1)));
+    }
+    println!();
+
+    let mut attempt = 0;
+    let input = loop {
+        match fetcher.fetch_challenge(year, day) {
+            Err(e) if attempt < UNLOCK_RETRIES && is_locked(&e) => {
+                attempt += 1;
+                thread::sleep(Duration::from_secs(1));
+            }
+            result => break result?,
+        }
+    };
+    println!("Cached the puzzle input ({} lines)", input.lines().count());
+
+    print!("{}", fetcher.fetch_puzzle(year, day)?);
+
+    Ok(())
+}
+
+const UNLOCK_RETRIES: u32 = 10;
+
+fn is_locked(e: &anyhow::Error) -> bool {
+    matches!(e.downcast_ref(), Some(FetchError::NotUnlocked(_)))
+}
+
+pub fn show(fetcher: &Fetcher, year: i32, day: i32) -> Result<()> {
+    unlock::ensure_day(year, day as u32)?;
+    let markdown = fetcher.fetch_puzzle(year, day as u32)?;
+    print!("{}", markdown);
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use std::io;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::{Outcome, Status};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Human-readable progress and a summary table
+    #[default]
+    Text,
+    Json,
+    Yaml,
+    Csv,
+}
+
+/// A single part's result as written by the machine-readable formats.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: i32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Seconds spent in the part, not counting parsing.
+    pub elapsed: Option<f64>,
+    pub input: String,
+    /// `pass`, `fail`, `unchecked` or `error`.
+    pub status: &'static str,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(outcome: Outcome, input: &str) -> Self {
+        let (status, error) = match outcome.status {
+            Status::Pass => ("pass", None),
+            Status::Fail(correct) => ("fail", Some(format!("expected {}", correct))),
+            Status::Unchecked => ("unchecked", None),
+            Status::Error(e) => ("error", Some(e)),
+        };
+
+        Self {
+            year: outcome.year,
+            day: outcome.day,
+            part: outcome.part,
+            answer: outcome.answer,
+            elapsed: outcome.elapsed.map(|e| e.as_secs_f64()),
+            input: input.to_string(),
+            status,
+            error,
+        }
+    }
+}
+
+/// Writes records to stdout. `Format::Text` is handled by the runner itself.
+pub fn write(format: Format, records: &[Record]) -> Result<()> {
+    write_to(io::stdout().lock(), format, records)
+}
+
+fn write_to(mut output: impl io::Write, format: Format, records: &[Record]) -> Result<()> {
+    match format {
+        Format::Text => {}
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut output, records)?;
+            writeln!(output)?;
+        }
+        Format::Yaml => serde_yaml::to_writer(output, records)?,
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(output);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let outcome = |part, status| Outcome {
+            year: 2024,
+            day: 1,
+            part,
+            answer: Some("42".to_string()),
+            elapsed: Some(Duration::from_millis(1500)),
+            status,
+        };
+
+        vec![
+            Record::new(outcome(1, Status::Pass), "puzzle input"),
+            Record::new(outcome(2, Status::Fail("41".to_string())), "puzzle input"),
+        ]
+    }
+
+    fn written(format: Format) -> String {
+        let mut output = Vec::new();
+        write_to(&mut output, format, &records()).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn records_carry_the_status_and_expected_answer() {
+        let [pass, fail] = records().try_into().unwrap();
+
+        assert_eq!((pass.status, pass.error), ("pass", None));
+        assert_eq!(fail.status, "fail");
+        assert_eq!(fail.error.as_deref(), Some("expected 41"));
+        assert_eq!(fail.elapsed, Some(1.5));
+
+        let error = Record::new(
+            Outcome {
+                year: 2024,
+                day: 2,
+                part: 1,
+                answer: None,
+                elapsed: None,
+                status: Status::Error("panicked".to_string()),
+            },
+            "stdin",
+        );
+        assert_eq!((error.status, error.answer), ("error", None));
+        assert_eq!(error.error.as_deref(), Some("panicked"));
+    }
+
+    #[test]
+    fn writes_csv_with_a_header() {
+        assert_eq!(
+            written(Format::Csv),
+            "year,day,part,answer,elapsed,input,status,error\n\
+             2024,1,1,42,1.5,puzzle input,pass,\n\
+             2024,1,2,42,1.5,puzzle input,fail,expected 41\n"
+        );
+    }
+
+    #[test]
+    fn writes_json_and_yaml_lists() {
+        let json: serde_json::Value = serde_json::from_str(&written(Format::Json)).unwrap();
+        assert_eq!(json[1]["status"], "fail");
+        assert_eq!(json[0]["error"], serde_json::Value::Null);
+
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&written(Format::Yaml)).unwrap();
+        assert_eq!(yaml[0]["answer"].as_str(), Some("42"));
+        assert_eq!(yaml[1]["error"].as_str(), Some("expected 41"));
+
+        assert_eq!(written(Format::Text), "");
+    }
+}
@@ -0,0 +1,149 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{ensure, Result};
+use serde::Serialize;
+
+use crate::{
+    challenge::{code_blocks, Fetcher},
+    unlock,
+};
+
+const YEAR_TEMPLATE: &str = "const YEAR: i32 = {year};
+
+include!(concat!(env!(\"OUT_DIR\"), \"/year{year}.rs\"));
+";
+
+const DAY_TEMPLATE: &str = "use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+
+use crate::solution::Solution;
+
+use super::YEAR;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = {number};
+    const TITLE: &'static str = \"{title}\";
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        solve_part1(challenge)
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        solve_part2(challenge)
+    }
+}
+
+fn solve_part1(_challenge: &str) -> Result<usize> {
+    Err(anyhow!(\"Day {number} part 1 is not solved yet\"))
+}
+
+fn solve_part2(_challenge: &str) -> Result<usize> {
+    Err(anyhow!(\"Day {number} part 2 is not solved yet\"))
+}
+";
+
+/// A fixture entry with the answers left for the solver to fill in.
+#[derive(Serialize)]
+struct Fixture<'a> {
+    input: &'a str,
+    part1: Option<&'a str>,
+    part2: Option<&'a str>,
+}
+
+/// The checkout to add the day to. This is the current directory rather than
+/// where the binary was built, which may be long gone after `cargo install`.
+fn root() -> Result<PathBuf> {
+    let root = std::env::current_dir()?;
+    ensure!(
+        root.join("Cargo.toml").exists(),
+        "Run this from the root of the repository, next to Cargo.toml"
+    );
+    Ok(root)
+}
+
+/// Takes the title out of the `## --- Day 1: Title ---` heading.
+fn title(markdown: &str) -> Option<&str> {
+    markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("## --- Day "))
+        .and_then(|heading| heading.split_once(": "))
+        .map(|(_, title)| title.trim_end_matches(" ---").trim())
+}
+
+/// Creates the module for a new day, and its year if needed. The build script
+/// picks the file up, so there is nothing else to register. The input and the
+/// puzzle's first example are fetched when the day is already unlocked.
+pub fn new_day(fetcher: &Fetcher, year: i32, day: u32) -> Result<()> {
+    unlock::ensure_day(year, day)?;
+
+    let root = root()?;
+    let dir = root.join("src").join(format!("year{}", year));
+    let path = dir.join(format!("day{:02}.rs", day));
+    ensure!(!path.exists(), "{} already exists", path.display());
+
+    fs::create_dir_all(&dir)?;
+    let module = dir.join("mod.rs");
+    if !module.exists() {
+        fs::write(&module, YEAR_TEMPLATE.replace("{year}", &year.to_string()))?;
+        println!("Created {}", module.display());
+    }
+
+    let puzzle = fetcher
+        .fetch_puzzle(year, day)
+        .inspect_err(|e| eprintln!("Could not fetch the puzzle description: {}", e))
+        .ok();
+
+    let source = DAY_TEMPLATE
+        .replace("{day}", &format!("{:02}", day))
+        .replace("{number}", &day.to_string())
+        .replace(
+            "{title}",
+            &puzzle
+                .as_deref()
+                .and_then(title)
+                .unwrap_or_default()
+                .replace('\\', "\\\\")
+                .replace('"', "\\\""),
+        );
+    fs::write(&path, source)?;
+    println!("Created {}", path.display());
+
+    match fetcher.fetch_challenge(year, day) {
+        Ok(_) => println!("Cached the puzzle input"),
+        Err(e) => eprintln!("Could not fetch the puzzle input: {}", e),
+    }
+
+    let example = puzzle.as_deref().map(code_blocks).unwrap_or_default();
+    if let Some(example) = example.first() {
+        let fixture = root
+            .join("fixtures")
+            .join(year.to_string())
+            .join(format!("{:02}.yaml", day));
+
+        if !fixture.exists() {
+            fs::create_dir_all(fixture.parent().unwrap())?;
+            let entry = Fixture {
+                input: example.trim(),
+                part1: None,
+                part2: None,
+            };
+            fs::write(&fixture, serde_yaml::to_string(&[entry])?)?;
+            println!(
+                "Created {} (fill in the expected answers)",
+                fixture.display()
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+/// Answers for part one and part two; a part is `None` when it was not run.
+pub type Answers = [Option<String>; 2];
+
+/// Answers from one run along with how long each step took.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answers: Answers,
+    pub parse: Duration,
+    pub parts: [Option<Duration>; 2],
+}
+
+/// An example input with its expected answers. A part left as `None` is not
+/// checked, since puzzles often give separate examples for each part.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(not(test), allow(dead_code))]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+/// A single day's puzzle. The runner fetches the input and hands it to
+/// `parse`, then passes the parsed value to each part.
+pub trait Solution {
+    const YEAR: i32;
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    /// Examples checked by `cargo test`, in addition to any under `fixtures/`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    const EXAMPLES: &'static [Example] = &[];
+
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<impl Display>;
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<impl Display>;
+}
+
+/// Object-safe view of a [`Solution`], so every day can live in one map.
+pub trait DynSolution: Sync {
+    fn year(&self) -> i32;
+    fn day(&self) -> u32;
+    fn title(&self) -> &'static str;
+    #[cfg(test)]
+    fn examples(&self) -> &'static [Example];
+    fn solve(&self, input: &str, part: Option<u8>) -> Result<Solved>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> i32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    #[cfg(test)]
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn solve(&self, input: &str, part: Option<u8>) -> Result<Solved> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse = start.elapsed();
+
+        let mut solved = Solved {
+            answers: [None, None],
+            parse,
+            parts: [None, None],
+        };
+
+        if part.is_none_or(|p| p == 1) {
+            let start = Instant::now();
+            solved.answers[0] = Some(S::part1(&parsed)?.to_string());
+            solved.parts[0] = Some(start.elapsed());
+        }
+        if part.is_none_or(|p| p == 2) {
+            let start = Instant::now();
+            solved.answers[1] = Some(S::part2(&parsed)?.to_string());
+            solved.parts[1] = Some(start.elapsed());
+        }
+
+        Ok(solved)
+    }
+}
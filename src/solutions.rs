@@ -1,49 +1,97 @@
-use std::collections::BTreeMap;
-
-use crate::{year2015, year2024, Solution};
-
-pub fn all() -> BTreeMap<i32, BTreeMap<i32, Solution>> {
-    BTreeMap::from([
-        (
-            2015,
-            BTreeMap::from([
-                (1, year2015::day01::solve as Solution),
-                (2, year2015::day02::solve),
-                (3, year2015::day03::solve),
-                (4, year2015::day04::solve),
-                (5, year2015::day05::solve),
-                (6, year2015::day06::solve),
-                (7, year2015::day07::solve),
-                (8, year2015::day08::solve),
-                (9, year2015::day09::solve),
-                (10, year2015::day10::solve),
-                (11, year2015::day11::solve),
-                (12, year2015::day12::solve),
-                (13, year2015::day13::solve),
-                (14, year2015::day14::solve),
-                (15, year2015::day15::solve),
-                (16, year2015::day16::solve),
-                (17, year2015::day17::solve),
-                (18, year2015::day18::solve),
-                (19, year2015::day19::solve),
-                (20, year2015::day20::solve),
-                (21, year2015::day21::solve),
-                (22, year2015::day22::solve),
-                (23, year2015::day23::solve),
-            ]),
-        ),
-        (
-            2024,
-            BTreeMap::from([
-                (1, year2024::day01::solve as Solution),
-                (2, year2024::day02::solve),
-                (3, year2024::day03::solve),
-                (4, year2024::day04::solve),
-                (5, year2024::day05::solve),
-                (6, year2024::day06::solve),
-                (7, year2024::day07::solve),
-                (8, year2024::day08::solve),
-            ]),
-        ),
-    ])
+use crate::{runner::Solutions, solution::Solution};
+
+/// Registers each day in the map returned by `all`, and generates a test per
+/// day that checks its examples. The invocation is generated by `build.rs`
+/// from the files under `src/yearNNNN/`.
+macro_rules! solutions {
+    ($($year:ident => [$($(#[$attr:meta])* $day:ident::$solution:ident),* $(,)?]),* $(,)?) => {
+        pub fn all() -> Solutions {
+            let mut solutions = Solutions::new();
+            $($(
+                register(&mut solutions, &crate::$year::$day::$solution);
+            )*)*
+            solutions
+        }
+
+        #[cfg(test)]
+        mod examples {
+            $(mod $year {
+                $(
+                    #[test]
+                    $(#[$attr])*
+                    fn $day() {
+                        crate::examples::check(&crate::$year::$day::$solution);
+                    }
+                )*
+            })*
+        }
+    };
+}
+
+/// Adds a day, panicking if another solution already claimed its year and
+/// day, which would otherwise silently replace it.
+fn register<S: Solution + Sync>(solutions: &mut Solutions, solution: &'static S) {
+    let replaced = solutions
+        .entry(S::YEAR)
+        .or_default()
+        .insert(S::DAY as i32, solution);
+
+    if let Some(existing) = replaced {
+        panic!(
+            "Year {} day {} is registered twice, by {:?} and {:?}",
+            S::YEAR,
+            S::DAY,
+            existing.title(),
+            S::TITLE
+        );
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use anyhow::Result;
+
+    use super::*;
+
+    struct First;
+    struct Second;
+
+    macro_rules! dummy {
+        ($name:ident, $title:literal) => {
+            impl Solution for $name {
+                const YEAR: i32 = 2015;
+                const DAY: u32 = 1;
+                const TITLE: &'static str = $title;
+
+                type Parsed<'a> = ();
+
+                fn parse(_: &str) -> Result<Self::Parsed<'_>> {
+                    Ok(())
+                }
+
+                fn part1(_: &Self::Parsed<'_>) -> Result<impl Display> {
+                    Ok(0)
+                }
+
+                fn part2(_: &Self::Parsed<'_>) -> Result<impl Display> {
+                    Ok(0)
+                }
+            }
+        };
+    }
+
+    dummy!(First, "First");
+    dummy!(Second, "Second");
+
+    #[test]
+    #[should_panic(expected = "Year 2015 day 1 is registered twice")]
+    fn registering_a_day_twice_panics() {
+        let mut solutions = Solutions::new();
+        register(&mut solutions, &First);
+        register(&mut solutions, &Second);
+    }
 }
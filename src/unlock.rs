@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use anyhow::{ensure, Result};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+mod days;
+
+pub use days::days_in;
+
+/// The first event was held in 2015.
+const FIRST_YEAR: i32 = 2015;
+
+/// Puzzles unlock at midnight US Eastern. December is always standard time,
+/// so a fixed offset is exact.
+fn eastern() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+/// Fails for a day outside the year's event, such as day 20 of 2025.
+pub fn ensure_day(year: i32, day: u32) -> Result<()> {
+    let days = days_in(year);
+    ensure!(
+        (1..=days).contains(&day),
+        "The {} event has days 1 to {}, so there is no day {}",
+        year,
+        days,
+        day
+    );
+    Ok(())
+}
+
+/// When a puzzle unlocks, or `None` for a day that is not part of the event.
+pub fn unlocks_at(year: i32, day: u32) -> Option<DateTime<Utc>> {
+    if year < FIRST_YEAR || !(1..=days_in(year)).contains(&day) {
+        return None;
+    }
+
+    eastern()
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .single()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// How long until a puzzle unlocks; `None` once it has, or if it never will.
+pub fn time_until(year: i32, day: u32, now: DateTime<Utc>) -> Option<Duration> {
+    (unlocks_at(year, day)? - now).to_std().ok()
+}
+
+pub fn is_unlocked(year: i32, day: u32, now: DateTime<Utc>) -> bool {
+    unlocks_at(year, day).is_some_and(|t| t <= now)
+}
+
+/// Formats a wait as `2d 03:04:05`, leaving out the days when there are none.
+pub fn countdown(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+
+    match seconds / 86400 {
+        0 => clock,
+        days => format!("{}d {}", days, clock),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        assert_eq!(
+            unlocks_at(2024, 1).unwrap().to_rfc3339(),
+            "2024-12-01T05:00:00+00:00"
+        );
+        assert_eq!(unlocks_at(2024, 26), None);
+        assert_eq!(unlocks_at(2014, 1), None);
+    }
+
+    #[test]
+    fn events_from_2025_have_twelve_days() {
+        assert!(unlocks_at(2025, 12).is_some());
+        assert_eq!(unlocks_at(2025, 13), None);
+        assert!(ensure_day(2024, 25).is_ok());
+        assert!(ensure_day(2025, 20).is_err());
+    }
+
+    #[test]
+    fn counts_down_to_the_unlock() {
+        let now = Utc.with_ymd_and_hms(2024, 11, 29, 4, 0, 0).unwrap();
+
+        assert!(!is_unlocked(2024, 1, now));
+        assert_eq!(countdown(time_until(2024, 1, now).unwrap()), "2d 01:00:00");
+        assert_eq!(time_until(2023, 1, now), None);
+    }
+}
@@ -0,0 +1,9 @@
+/// Events ran for 25 days until 2024 and for 12 days from 2025 on. The build
+/// script includes this file too, so it cannot use any crates.
+pub fn days_in(year: i32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
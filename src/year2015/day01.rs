@@ -2,27 +2,65 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 1)?;
+pub struct Day01;
 
-    let mut floor: i32 = 0;
-    let mut instruction_count = 1;
-    let mut negative_floor_instruction = 0;
+impl Solution for Day01 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
 
-    challenge.chars().for_each(|c| {
-        match c {
-            '(' => floor += 1,
-            ')' => floor -= 1,
-            _ => (),
-        }
-        if negative_floor_instruction == 0 && floor == -1 {
-            negative_floor_instruction = instruction_count;
-        }
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "(())",
+            part1: Some("0"),
+            part2: None,
+        },
+        Example {
+            input: "))(((((",
+            part1: Some("3"),
+            part2: None,
+        },
+        Example {
+            input: ")",
+            part1: None,
+            part2: Some("1"),
+        },
+        Example {
+            input: "()())",
+            part1: None,
+            part2: Some("5"),
+        },
+    ];
 
-        instruction_count += 1;
-    });
+    type Parsed<'a> = Vec<i32>;
 
-    Ok((Box::new(floor), Box::new(negative_floor_instruction)))
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input
+            .chars()
+            .scan(0, |floor, c| {
+                match c {
+                    '(' => *floor += 1,
+                    ')' => *floor -= 1,
+                    _ => (),
+                }
+                Some(*floor)
+            })
+            .collect())
+    }
+
+    fn part1(floors: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(floors.last().copied().unwrap_or(0))
+    }
+
+    fn part2(floors: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(floors
+            .iter()
+            .position(|floor| *floor == -1)
+            .map_or(0, |i| i + 1))
+    }
 }
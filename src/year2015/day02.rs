@@ -2,34 +2,72 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 2)?;
+pub struct Day02;
 
-    let mut area = 0;
-    let mut ribbon_length = 0;
+impl Solution for Day02 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
 
-    challenge.lines().for_each(|line| {
-        if line.is_empty() {
-            return;
-        }
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "2x3x4",
+            part1: Some("58"),
+            part2: Some("34"),
+        },
+        Example {
+            input: "1x1x10",
+            part1: Some("43"),
+            part2: Some("14"),
+        },
+    ];
 
-        let dimensions: Vec<&str> = line.split('x').collect();
+    type Parsed<'a> = Vec<(u32, u32, u32)>;
 
-        let length = dimensions[0].parse::<u32>().unwrap();
-        let width = dimensions[1].parse::<u32>().unwrap();
-        let height = dimensions[2].parse::<u32>().unwrap();
-        let smallest_dimension = (length * width).min(width * height).min(height * length);
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let dimensions: Vec<&str> = line.split('x').collect();
 
-        let lwface = length * width;
-        let whface = width * height;
-        let hlface = height * length;
-        let smallest_face = (length + width).min(width + height).min(height + length);
+                let length = dimensions[0].parse::<u32>().unwrap();
+                let width = dimensions[1].parse::<u32>().unwrap();
+                let height = dimensions[2].parse::<u32>().unwrap();
 
-        area += 2 * lwface + 2 * whface + 2 * hlface + smallest_dimension;
-        ribbon_length += 2 * smallest_face + length * width * height;
-    });
+                (length, width, height)
+            })
+            .collect())
+    }
 
-    Ok((Box::new(area), Box::new(ribbon_length)))
+    fn part1(presents: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(presents
+            .iter()
+            .map(|&(length, width, height)| {
+                let smallest_dimension = (length * width).min(width * height).min(height * length);
+
+                let lwface = length * width;
+                let whface = width * height;
+                let hlface = height * length;
+
+                2 * lwface + 2 * whface + 2 * hlface + smallest_dimension
+            })
+            .sum::<u32>())
+    }
+
+    fn part2(presents: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(presents
+            .iter()
+            .map(|&(length, width, height)| {
+                let smallest_face = (length + width).min(width + height).min(height + length);
+
+                2 * smallest_face + length * width * height
+            })
+            .sum::<u32>())
+    }
 }
@@ -2,15 +2,49 @@ use std::{collections::HashSet, fmt::Display};
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 3)?;
-
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: ">",
+            part1: Some("2"),
+            part2: None,
+        },
+        Example {
+            input: "^>v<",
+            part1: Some("4"),
+            part2: Some("3"),
+        },
+        Example {
+            input: "^v^v^v^v^v",
+            part1: Some("2"),
+            part2: Some("11"),
+        },
+    ];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> usize {
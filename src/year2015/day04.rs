@@ -2,36 +2,48 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 4)?;
+pub struct Day04;
 
-    let mut five_key = 0;
-    let mut six_key = 0;
+impl Solution for Day04 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
 
-    let five_compare = 0x00000fff;
-    let six_compare = 0x000000ff;
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "abcdef",
+        part1: Some("609043"),
+        part2: None,
+    }];
 
-    for i in 0..u32::MAX {
-        let data = format!("{}{}", challenge.trim(), i);
-        let digest = md5::compute(data.as_bytes());
+    type Parsed<'a> = &'a str;
 
-        let check: u32 = u32::from_be_bytes(digest[..4].try_into().unwrap());
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input.trim())
+    }
 
-        if check < five_compare {
-            if five_key == 0 {
-                five_key = i;
-            }
-            if check < six_compare {
-                six_key = i;
-            }
-        }
+    fn part1(secret: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(find_key(secret, 0x00000fff))
+    }
 
-        if five_key != 0 && six_key != 0 {
-            break;
-        }
+    fn part2(secret: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(find_key(secret, 0x000000ff))
     }
+}
+
+fn find_key(secret: &str, compare: u32) -> u32 {
+    (0..u32::MAX)
+        .find(|i| {
+            let data = format!("{}{}", secret, i);
+            let digest = md5::compute(data.as_bytes());
+
+            let check: u32 = u32::from_be_bytes(digest[..4].try_into().unwrap());
 
-    Ok((Box::new(five_key), Box::new(six_key)))
+            check < compare
+        })
+        .unwrap_or(0)
 }
@@ -3,15 +3,44 @@ use std::fmt::Display;
 use anyhow::Result;
 use fancy_regex::Regex;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 5)?;
+pub struct Day05;
 
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+impl Solution for Day05 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "ugknbfddgicrmopn\naaa\njchzalrnumimnmhp\nhaegwjzuvuyypxyu\ndvszwmarrgswjxmb",
+            part1: Some("2"),
+            part2: None,
+        },
+        Example {
+            input: "qjhvhtzxzqqjkmpb\nxxyxx\nuurcxstgmygtbstg\nieodomkazucvgmuy",
+            part1: None,
+            part2: Some("2"),
+        },
+    ];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> i32 {
@@ -5,15 +5,44 @@ use std::{
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 6)?;
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "turn on 0,0 through 999,999\ntoggle 0,0 through 999,0\nturn off 499,499 through 500,500",
+            part1: Some("998996"),
+            part2: None,
+        },
+        Example {
+            input: "turn on 0,0 through 0,0\ntoggle 0,0 through 999,999",
+            part1: None,
+            part2: Some("2000001"),
+        },
+    ];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> usize {
@@ -2,15 +2,39 @@ use anyhow::Result;
 use fancy_regex::Regex;
 use std::{collections::HashMap, fmt::Display};
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 7)?;
+pub struct Day07;
 
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+impl Solution for Day07 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nd LSHIFT 2 -> f\nNOT e -> g\nf OR g -> a",
+            part1: Some("65316"),
+            part2: None,
+        },
+    ];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> u16 {
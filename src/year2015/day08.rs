@@ -1,17 +1,42 @@
 use std::fmt::Display;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
 use anyhow::Result;
 use fancy_regex::Regex;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 8)?;
+pub struct Day08;
 
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+impl Solution for Day08 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Matchsticks";
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#,
+        part1: Some("12"),
+        part2: Some("19"),
+    }];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> String {
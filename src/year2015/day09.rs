@@ -2,45 +2,63 @@ use anyhow::Result;
 use itertools::Itertools;
 use std::{collections::BTreeMap, fmt::Display};
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 9)?;
+pub struct Day09;
 
-    let mut cities = BTreeMap::<&str, BTreeMap<&str, u32>>::new();
+impl Solution for Day09 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "All in a Single Night";
 
-    challenge.lines().for_each(|line| {
-        let parts = line.split_whitespace().collect::<Vec<&str>>();
-        let from = parts[0];
-        let to = parts[2];
-        let distance = parts[4].parse::<u32>().unwrap();
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141",
+        part1: Some("605"),
+        part2: Some("982"),
+    }];
 
-        if !cities.contains_key(from) {
-            cities.insert(from, BTreeMap::new());
-        }
+    type Parsed<'a> = Distances<'a>;
 
-        if !cities.contains_key(to) {
-            cities.insert(to, BTreeMap::new());
-        }
+    /// Distances between each pair of cities, in both directions.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let mut cities = Distances::new();
 
-        cities.get_mut(from).unwrap().insert(to, distance);
-        cities.get_mut(to).unwrap().insert(from, distance);
-    });
+        input.lines().for_each(|line| {
+            let parts = line.split_whitespace().collect::<Vec<&str>>();
+            let from = parts[0];
+            let to = parts[2];
+            let distance = parts[4].parse::<u32>().unwrap();
 
-    let mut min_distance = u32::MAX;
-    let mut max_distance = 0;
+            cities.entry(from).or_default().insert(to, distance);
+            cities.entry(to).or_default().insert(from, distance);
+        });
+
+        Ok(cities)
+    }
+
+    fn part1(cities: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(route_lengths(cities).min().unwrap_or(u32::MAX))
+    }
+
+    fn part2(cities: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(route_lengths(cities).max().unwrap_or(0))
+    }
+}
+
+type Distances<'a> = BTreeMap<&'a str, BTreeMap<&'a str, u32>>;
 
+/// Total distance of every possible route.
+fn route_lengths<'a>(cities: &'a Distances<'_>) -> impl Iterator<Item = u32> + 'a {
     cities
         .keys()
         .permutations(cities.len())
-        .for_each(|permutation| {
-            let distance = permutation
+        .map(|permutation| {
+            permutation
                 .windows(2)
                 .map(|pair| cities[pair[0]][pair[1]])
-                .sum::<u32>();
-
-            min_distance = min_distance.min(distance);
-            max_distance = max_distance.max(distance);
-        });
-    Ok((Box::new(min_distance), Box::new(max_distance)))
+                .sum::<u32>()
+        })
 }
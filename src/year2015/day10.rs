@@ -2,15 +2,28 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{solution::Solution, year2015::YEAR};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 10)?;
+pub struct Day10;
 
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+impl Solution for Day10 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn look_and_say(input: &str, times: usize) -> String {
@@ -2,14 +2,37 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 11)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Corporate Policy";
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "abcdefgh",
+        part1: Some("abcdffaa"),
+        part2: None,
+    }];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> String {
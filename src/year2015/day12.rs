@@ -3,14 +3,49 @@ use std::fmt::Display;
 use anyhow::Result;
 use serde_json::Value;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 12)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "[1,2,3]",
+            part1: Some("6"),
+            part2: Some("6"),
+        },
+        Example {
+            input: r#"[1,{"c":"red","b":2},3]"#,
+            part1: Some("6"),
+            part2: Some("4"),
+        },
+        Example {
+            input: r#"{"d":"red","e":[1,2,3,4],"f":5}"#,
+            part1: Some("15"),
+            part2: Some("0"),
+        },
+    ];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> String {
@@ -3,14 +3,28 @@ use std::{collections::BTreeMap, fmt::Display, iter::once};
 use anyhow::Result;
 use itertools::Itertools;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 13)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{solution::Solution, year2015::YEAR};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Knights of the Dinner Table";
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> String {
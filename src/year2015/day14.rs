@@ -2,17 +2,31 @@ use std::{collections::BTreeMap, fmt::Display};
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 14)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{solution::Solution, year2015::YEAR};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Reindeer Olympics";
+
+    type Parsed<'a> = BTreeMap<String, Stat>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(reindeer_stats(input))
+    }
+
+    fn part1(stats: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(stats))
+    }
+
+    fn part2(stats: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(stats))
+    }
 }
 
-struct Stat {
+pub struct Stat {
     speed: i32,
     fly_time: i32,
     rest_time: i32,
@@ -36,8 +50,8 @@ impl Stat {
     }
 }
 
-fn solve_part1(challenge: &str) -> String {
-    reindeer_stats(challenge)
+fn solve_part1(stats: &BTreeMap<String, Stat>) -> String {
+    stats
         .values()
         .map(|stat| stat.distance(2503))
         .max()
@@ -57,14 +71,8 @@ fn reindeer_stats(challenge: &str) -> BTreeMap<String, Stat> {
     }))
 }
 
-fn solve_part2(challenge: &str) -> String {
-    let mut points = BTreeMap::from_iter(
-        reindeer_stats(challenge)
-            .keys()
-            .map(|name| (name.to_string(), 0)),
-    );
-
-    let stats = reindeer_stats(challenge);
+fn solve_part2(stats: &BTreeMap<String, Stat>) -> String {
+    let mut points = BTreeMap::from_iter(stats.keys().map(|name| (name.to_string(), 0)));
 
     for time in 1..=2503 {
         let distances: BTreeMap<_, _> = stats
@@ -2,17 +2,31 @@ use std::{collections::BTreeMap, fmt::Display};
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 15)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{solution::Solution, year2015::YEAR};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Science for Hungry People";
+
+    type Parsed<'a> = BTreeMap<String, Ingredient>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse_ingredients(input))
+    }
+
+    fn part1(ingredients: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(ingredients))
+    }
+
+    fn part2(ingredients: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(ingredients))
+    }
 }
 
-struct Ingredient {
+pub struct Ingredient {
     pub c: i32,
     pub d: i32,
     pub f: i32,
@@ -32,9 +46,8 @@ impl Ingredient {
     }
 }
 
-fn solve_part1(challenge: &str) -> String {
-    let binding = parse_ingredients(challenge);
-    let ing = binding.values().collect::<Vec<_>>();
+fn solve_part1(ingredients: &BTreeMap<String, Ingredient>) -> String {
+    let ing = ingredients.values().collect::<Vec<_>>();
     let mut best_score = 0;
 
     for i in 10..40 {
@@ -109,9 +122,8 @@ fn parse_ingredients(challenge: &str) -> BTreeMap<String, Ingredient> {
     }))
 }
 
-fn solve_part2(challenge: &str) -> String {
-    let binding = parse_ingredients(challenge);
-    let ing = binding.values().collect::<Vec<_>>();
+fn solve_part2(ingredients: &BTreeMap<String, Ingredient>) -> String {
+    let ing = ingredients.values().collect::<Vec<_>>();
     let mut best_score = 0;
 
     for i in 10..40 {
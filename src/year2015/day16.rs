@@ -2,17 +2,31 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{solution::Solution, year2015::YEAR};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 16)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Aunt Sue";
+
+    type Parsed<'a> = Vec<Aunt>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse_aunts(input))
+    }
+
+    fn part1(aunts: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(aunts))
+    }
+
+    fn part2(aunts: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(aunts))
+    }
 }
 
-struct Aunt {
+pub struct Aunt {
     pub id: i32,
     pub children: Option<i32>,
     pub cats: Option<i32>,
@@ -76,8 +90,8 @@ fn parse_aunts(challenge: &str) -> Vec<Aunt> {
     challenge.lines().map(Aunt::from_string).collect()
 }
 
-fn solve_part1(challenge: &str) -> String {
-    parse_aunts(challenge)
+fn solve_part1(aunts: &[Aunt]) -> String {
+    aunts
         .iter()
         .find(|aunt| {
             let criteria = &Aunt::from_string("Sue 0: children: 3, cats: 7, samoyeds: 2, pomeranians: 3, akitas: 0, vizslas: 0, goldfish: 5, trees: 3, cars: 2, perfumes: 1");
@@ -129,8 +143,8 @@ fn solve_part1(challenge: &str) -> String {
         .to_string()
 }
 
-fn solve_part2(challenge: &str) -> String {
-    parse_aunts(challenge)
+fn solve_part2(aunts: &[Aunt]) -> String {
+    aunts
         .iter()
         .find(|aunt| {
             let criteria = &Aunt::from_string("Sue 0: children: 3, cats: 7, samoyeds: 2, pomeranians: 3, akitas: 0, vizslas: 0, goldfish: 5, trees: 3, cars: 2, perfumes: 1");
@@ -7,14 +7,44 @@ use anyhow::Result;
 use itertools::Itertools;
 use regex::{Captures, Regex};
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 19)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Medicine for Rudolph";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "H => HO\nH => OH\nO => HH\n\nHOH",
+            part1: Some("4"),
+            part2: None,
+        },
+        Example {
+            input: "H => HO\nH => OH\nO => HH\n\nHOHOHO",
+            part1: Some("7"),
+            part2: None,
+        },
+    ];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> String {
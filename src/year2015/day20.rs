@@ -2,19 +2,48 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 20)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{
+    solution::{Example, Solution},
+    year2015::YEAR,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Infinite Elves and Infinite Houses";
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "150",
+        part1: Some("8"),
+        part2: None,
+    }];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn num_presents_part1(n: usize) -> usize {
     (1..=(n as f64).sqrt() as usize)
-        .flat_map(|i| if n % i == 0 { vec![i, n / i] } else { vec![] })
+        .flat_map(|i| {
+            if n.is_multiple_of(i) {
+                vec![i, n / i]
+            } else {
+                vec![]
+            }
+        })
         .map(|i| 10 * i)
         .sum::<usize>()
 }
@@ -28,7 +57,7 @@ fn solve_part1(challenge: &str) -> String {
 
 fn num_presents_part2(n: usize) -> usize {
     (1..=(n as f64).sqrt() as usize)
-        .filter(|&i| n % i == 0)
+        .filter(|&i| n.is_multiple_of(i))
         .flat_map(|i| {
             let mut divisors = Vec::new();
             if n / i <= 50 {
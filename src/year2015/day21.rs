@@ -2,14 +2,28 @@ use std::{collections::BTreeSet, fmt::Display};
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 21)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{solution::Solution, year2015::YEAR};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "RPG Simulator 20XX";
+
+    type Parsed<'a> = Boss;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse_boss(input))
+    }
+
+    fn part1(boss: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(boss))
+    }
+
+    fn part2(boss: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(boss))
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone)]
@@ -26,7 +40,7 @@ struct Loadout {
     rings: [Option<Item>; 2],
 }
 
-struct Boss {
+pub struct Boss {
     hp: usize,
     dmg: usize,
     def: usize,
@@ -55,8 +69,8 @@ impl Battle for Loadout {
         let damage = self.damage().saturating_sub(boss.def).max(1);
         let boss_damage = boss.dmg.saturating_sub(self.armor()).max(1);
 
-        let turns = (boss.hp + damage - 1) / damage;
-        let boss_turns = (100 + boss_damage - 1) / boss_damage;
+        let turns = boss.hp.div_ceil(damage);
+        let boss_turns = 100_usize.div_ceil(boss_damage);
 
         turns <= boss_turns
     }
@@ -218,21 +232,19 @@ fn parse_boss(challenge: &str) -> Boss {
     )
 }
 
-fn solve_part1(challenge: &str) -> usize {
-    let boss = parse_boss(challenge);
+fn solve_part1(boss: &Boss) -> usize {
     all_loadouts()
         .into_iter()
-        .find(|l| l.defeats(&boss))
+        .find(|l| l.defeats(boss))
         .unwrap()
         .cost()
 }
 
-fn solve_part2(challenge: &str) -> usize {
-    let boss = parse_boss(challenge);
+fn solve_part2(boss: &Boss) -> usize {
     all_loadouts()
         .iter()
         .rev()
-        .find(|l| !l.defeats(&boss))
+        .find(|l| !l.defeats(boss))
         .unwrap()
         .cost()
 }
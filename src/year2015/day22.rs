@@ -5,14 +5,28 @@ use std::{
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
-
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 22)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+use crate::{solution::Solution, year2015::YEAR};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 22;
+    const TITLE: &'static str = "Wizard Simulator 20XX";
+
+    type Parsed<'a> = (i32, i32);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(boss: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(boss))
+    }
+
+    fn part2(boss: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(boss))
+    }
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Hash)]
@@ -186,8 +200,7 @@ fn parse(challenge: &str) -> (i32, i32) {
     })
 }
 
-fn solve_part1(challenge: &str) -> i32 {
-    let (boss_hp, boss_damage) = parse(challenge);
+fn solve_part1(&(boss_hp, boss_damage): &(i32, i32)) -> i32 {
     let game_start = Game::new(boss_hp, boss_damage, false);
     let mut queue = BinaryHeap::new();
     let mut seen = HashSet::new();
@@ -209,8 +222,7 @@ fn solve_part1(challenge: &str) -> i32 {
     0
 }
 
-fn solve_part2(challenge: &str) -> i32 {
-    let (boss_hp, boss_damage) = parse(challenge);
+fn solve_part2(&(boss_hp, boss_damage): &(i32, i32)) -> i32 {
     let game_start = Game::new(boss_hp, boss_damage, true);
     let mut queue = BinaryHeap::new();
     let mut seen = HashSet::new();
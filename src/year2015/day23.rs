@@ -2,18 +2,32 @@ use std::{collections::HashMap, fmt::Display};
 
 use anyhow::Result;
 
-use crate::{challenge::Fetcher, year2015::YEAR};
+use crate::{solution::Solution, year2015::YEAR};
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 23)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "Opening the Turing Lock";
+
+    type Parsed<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(program: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(program))
+    }
+
+    fn part2(program: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(program))
+    }
 }
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     Hlf(char),
     Tpl(char),
     Inc(char),
@@ -46,8 +60,7 @@ fn parse(challenge: &str) -> Vec<Instruction> {
         .collect()
 }
 
-fn solve_part1(challenge: &str) -> i32 {
-    let program = parse(challenge);
+fn solve_part1(program: &[Instruction]) -> i32 {
     let mut state = HashMap::new();
     let mut ptr = 0;
     while ptr >= 0 && ptr < program.len() as i32 {
@@ -85,8 +98,7 @@ fn solve_part1(challenge: &str) -> i32 {
     *state.get(&'b').unwrap()
 }
 
-fn solve_part2(challenge: &str) -> i64 {
-    let program = parse(challenge);
+fn solve_part2(program: &[Instruction]) -> i64 {
     let mut state = HashMap::new();
     state.insert(&'a', 1);
     let mut ptr = 0;
@@ -6,16 +6,30 @@ use std::{
 use anyhow::Result;
 use itertools::Itertools;
 
-use crate::challenge::Fetcher;
+use crate::solution::Solution;
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 1)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn parse_line(line: &str) -> (i64, i64) {
@@ -2,16 +2,30 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::challenge::Fetcher;
+use crate::solution::Solution;
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 2)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn parse(line: &str) -> Vec<i32> {
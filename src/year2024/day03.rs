@@ -3,16 +3,43 @@ use std::fmt::Display;
 use anyhow::Result;
 use fancy_regex::Regex;
 
-use crate::challenge::Fetcher;
+use crate::solution::{Example, Solution};
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 3)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
+            part1: Some("161"),
+            part2: None,
+        },
+        Example {
+            input: "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+            part1: None,
+            part2: Some("48"),
+        },
+    ];
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(challenge))
+    }
+
+    fn part2(challenge: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(challenge))
+    }
 }
 
 fn solve_part1(challenge: &str) -> String {
@@ -2,16 +2,30 @@ use std::fmt::Display;
 
 use anyhow::Result;
 
-use crate::challenge::Fetcher;
+use crate::solution::Solution;
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 4)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Parsed<'a> = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse_word_search(input))
+    }
+
+    fn part1(word_search: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(word_search))
+    }
+
+    fn part2(word_search: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(word_search))
+    }
 }
 
 trait WordSearch {
@@ -78,7 +92,7 @@ impl WordSearch for Vec<Vec<char>> {
 
 impl XWordSearch for Vec<Vec<char>> {
     fn find_all_x(&self, word: &str) -> Vec<(i32, i32)> {
-        if word.len() % 2 == 0 {
+        if word.len().is_multiple_of(2) {
             return vec![];
         }
         let axes = [((-1, -1), (1, 1)), ((-1, 1), (1, -1))];
@@ -153,16 +167,10 @@ fn parse_word_search(challenge: &str) -> Vec<Vec<char>> {
         .collect()
 }
 
-fn solve_part1(challenge: &str) -> String {
-    parse_word_search(challenge)
-        .find_all("XMAS")
-        .len()
-        .to_string()
+fn solve_part1(word_search: &Vec<Vec<char>>) -> String {
+    word_search.find_all("XMAS").len().to_string()
 }
 
-fn solve_part2(challenge: &str) -> String {
-    parse_word_search(challenge)
-        .find_all_x("MAS")
-        .len()
-        .to_string()
+fn solve_part2(word_search: &Vec<Vec<char>>) -> String {
+    word_search.find_all_x("MAS").len().to_string()
 }
@@ -6,16 +6,30 @@ use std::{
 use anyhow::Result;
 use itertools::Itertools;
 
-use crate::challenge::Fetcher;
+use crate::solution::Solution;
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 5)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Parsed<'a> = (HashMap<usize, Vec<usize>>, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(manual: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(manual))
+    }
+
+    fn part2(manual: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(manual))
+    }
 }
 
 fn parse(challenge: &str) -> (HashMap<usize, Vec<usize>>, Vec<Vec<usize>>) {
@@ -50,7 +64,7 @@ impl RuleSet for HashMap<usize, Vec<usize>> {
             .try_fold(HashSet::new(), |mut seen, page| {
                 let allowed = !seen
                     .iter()
-                    .any(|seen| self.get(seen).map_or(false, |set| set.contains(page)));
+                    .any(|seen| self.get(seen).is_some_and(|set| set.contains(page)));
                 seen.insert(*page);
                 allowed.then_some(seen)
             })
@@ -62,8 +76,7 @@ fn middle_of(pages: &[usize]) -> usize {
     *pages.get(pages.len() / 2).unwrap()
 }
 
-fn solve_part1(challenge: &str) -> usize {
-    let (rule_set, page_updates) = parse(challenge);
+fn solve_part1((rule_set, page_updates): &(HashMap<usize, Vec<usize>>, Vec<Vec<usize>>)) -> usize {
     page_updates
         .iter()
         .filter(|pages| rule_set.allows(pages))
@@ -84,12 +97,11 @@ fn fix_pages(rule_set: &HashMap<usize, Vec<usize>>, pages: &[usize]) -> Vec<usiz
     })
 }
 
-fn solve_part2(challenge: &str) -> usize {
-    let (rule_set, page_updates) = parse(challenge);
+fn solve_part2((rule_set, page_updates): &(HashMap<usize, Vec<usize>>, Vec<Vec<usize>>)) -> usize {
     page_updates
         .iter()
         .filter(|pages| !rule_set.allows(pages))
-        .map(|pages| fix_pages(&rule_set, pages))
+        .map(|pages| fix_pages(rule_set, pages))
         .map(|pages| middle_of(&pages))
         .sum()
 }
@@ -2,22 +2,36 @@ use std::{collections::HashSet, fmt::Display};
 
 use anyhow::Result;
 
-use crate::challenge::Fetcher;
+use crate::solution::Solution;
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 6)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Parsed<'a> = RestrictedArea;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(ra: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(ra))
+    }
+
+    fn part2(ra: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(ra))
+    }
 }
 
 type Position = (i32, i32);
 
 #[derive(Debug)]
-struct RestrictedArea {
+pub struct RestrictedArea {
     obstacles: HashSet<Position>,
     guard_pos: Position,
     bounds: (i32, i32),
@@ -80,8 +94,7 @@ fn next_pos(guard_pos: (i32, i32), guard: char) -> (i32, i32) {
     }
 }
 
-fn solve_part1(challenge: &str) -> usize {
-    let ra = parse(challenge);
+fn solve_part1(ra: &RestrictedArea) -> usize {
     let mut guard_pos = ra.guard_pos;
     let mut guard = ra.guard;
     let mut visited = HashSet::new();
@@ -132,9 +145,7 @@ fn would_loop(
     false
 }
 
-fn solve_part2(challenge: &str) -> usize {
-    let ra = parse(challenge);
-
+fn solve_part2(ra: &RestrictedArea) -> usize {
     let mut guard_pos = ra.guard_pos;
     let mut guard = ra.guard;
     let mut visited = HashSet::new();
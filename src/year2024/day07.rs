@@ -3,16 +3,30 @@ use std::{fmt::Display, iter};
 use anyhow::Result;
 use itertools::Itertools;
 
-use crate::challenge::Fetcher;
+use crate::solution::Solution;
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 7)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Parsed<'a> = Vec<(usize, Vec<usize>)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(equations: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(equations))
+    }
+
+    fn part2(equations: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(equations))
+    }
 }
 
 fn parse(challenge: &str) -> Vec<(usize, Vec<usize>)> {
@@ -63,13 +77,12 @@ fn can_equal_target(target: &usize, constants: &[usize], operators: &[&Operator]
         .is_some_and(|i| i.eq(target))
 }
 
-fn solve_part1(challenge: &str) -> usize {
+fn solve_part1(equations: &[(usize, Vec<usize>)]) -> usize {
     let operators = [Operator::Add, Operator::Multiply];
-    parse(challenge)
+    equations
         .iter()
         .filter(|(target, constants)| {
-            iter::repeat(&operators)
-                .take(constants.len() - 1)
+            iter::repeat_n(&operators, constants.len() - 1)
                 .multi_cartesian_product()
                 .any(|operators| can_equal_target(target, constants, &operators))
         })
@@ -77,13 +90,12 @@ fn solve_part1(challenge: &str) -> usize {
         .sum()
 }
 
-fn solve_part2(challenge: &str) -> usize {
+fn solve_part2(equations: &[(usize, Vec<usize>)]) -> usize {
     let operators = [Operator::Add, Operator::Multiply, Operator::Concat];
-    parse(challenge)
+    equations
         .iter()
         .filter(|(target, constants)| {
-            iter::repeat(&operators)
-                .take(constants.len() - 1)
+            iter::repeat_n(&operators, constants.len() - 1)
                 .multi_cartesian_product()
                 .any(|operators| can_equal_target(target, constants, &operators))
         })
@@ -6,19 +6,33 @@ use std::{
 use anyhow::Result;
 use itertools::Itertools;
 
-use crate::challenge::Fetcher;
+use crate::solution::Solution;
 
 use super::YEAR;
 
-pub fn solve(fetcher: &Fetcher) -> Result<(Box<dyn Display>, Box<dyn Display>)> {
-    let challenge = fetcher.fetch_challenge(YEAR, 8)?;
-    Ok((
-        Box::new(solve_part1(&challenge)),
-        Box::new(solve_part2(&challenge)),
-    ))
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: i32 = YEAR;
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Parsed<'a> = Airwaves;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(airwaves: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part1(airwaves))
+    }
+
+    fn part2(airwaves: &Self::Parsed<'_>) -> Result<impl Display> {
+        Ok(solve_part2(airwaves))
+    }
 }
 
-struct Airwaves((i32, i32), HashMap<char, Vec<(i32, i32)>>);
+pub struct Airwaves((i32, i32), HashMap<char, Vec<(i32, i32)>>);
 
 fn parse(challenge: &str) -> Airwaves {
     let lines: Vec<&str> = challenge.lines().collect();
@@ -46,11 +60,10 @@ fn adjust(o: &(i32, i32), a: &(i32, i32)) -> (i32, i32) {
     (o.0 + a.0, o.1 + a.1)
 }
 
-fn solve_part1(challenge: &str) -> usize {
-    let Airwaves(bounds, antennae) = parse(challenge);
+fn solve_part1(Airwaves(bounds, antennae): &Airwaves) -> usize {
     antennae
-        .iter()
-        .flat_map(|(_, locations)| {
+        .values()
+        .flat_map(|locations| {
             locations.iter().combinations(2).flat_map(|combo| {
                 let p1 = combo.first().unwrap();
                 let p2 = combo.last().unwrap();
@@ -61,16 +74,15 @@ fn solve_part1(challenge: &str) -> usize {
                 [adjust(p1, &a1), adjust(p2, &a2)]
             })
         })
-        .filter(|l| in_bounds(l, &bounds))
+        .filter(|l| in_bounds(l, bounds))
         .collect::<HashSet<_>>()
         .len()
 }
 
-fn solve_part2(challenge: &str) -> usize {
-    let Airwaves(bounds, antennae) = parse(challenge);
+fn solve_part2(Airwaves(bounds, antennae): &Airwaves) -> usize {
     antennae
-        .iter()
-        .flat_map(|(_, locations)| {
+        .values()
+        .flat_map(|locations| {
             locations.iter().combinations(2).flat_map(|combo| {
                 let mut antinodes = HashSet::new();
 
@@ -85,14 +97,14 @@ fn solve_part2(challenge: &str) -> usize {
 
                 let mut p1 = adjust(p1, &a1);
 
-                while in_bounds(&p1, &bounds) {
+                while in_bounds(&p1, bounds) {
                     antinodes.insert(p1);
                     p1 = adjust(&p1, &a1);
                 }
 
                 let mut p2 = adjust(p2, &a2);
 
-                while in_bounds(&p2, &bounds) {
+                while in_bounds(&p2, bounds) {
                     antinodes.insert(p2);
                     p2 = adjust(&p2, &a2);
                 }
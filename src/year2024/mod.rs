@@ -1,10 +1,3 @@
 const YEAR: i32 = 2024;
 
-pub(crate) mod day01;
-pub(crate) mod day02;
-pub(crate) mod day03;
-pub(crate) mod day04;
-pub(crate) mod day05;
-pub(crate) mod day06;
-pub(crate) mod day07;
-pub(crate) mod day08;
+include!(concat!(env!("OUT_DIR"), "/year2024.rs"));